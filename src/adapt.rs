@@ -0,0 +1,203 @@
+//! Serialize-only views that reshape elements while joining them.
+//!
+//! ```rust
+//! use serde_cs::vec::CS;
+//!
+//! let cs = CS::new(vec!["alice@example.com".to_string(), "bob@example.com".to_string()]);
+//! let masked = cs.map_display(|s| s.split('@').next().unwrap_or_default().to_string());
+//! assert_eq!(serde_json::to_string(&masked).unwrap(), r#""alice,bob""#);
+//! assert_eq!(cs.0.len(), 2);
+//!
+//! let cs = CS::new(vec!["a", "", "b"]);
+//! let non_empty = cs.filter_display(|s| !s.is_empty());
+//! assert_eq!(serde_json::to_string(&non_empty).unwrap(), r#""a,b""#);
+//! ```
+
+use serde::ser;
+
+use std::fmt;
+
+/// Formats `f(element)` for every element instead of the element itself.
+#[derive(Clone, Copy)]
+pub struct Mapped<'a, T, F> {
+    items: &'a [T],
+    f: F,
+}
+
+impl<'a, T, F, U> Mapped<'a, T, F>
+where
+    F: Fn(&T) -> U,
+    U: fmt::Display,
+{
+    #[inline]
+    pub fn new(items: &'a [T], f: F) -> Self {
+        Self { items, f }
+    }
+}
+
+impl<T, F, U> fmt::Display for Mapped<'_, T, F>
+where
+    F: Fn(&T) -> U,
+    U: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.items.iter().map(&self.f);
+        if let Some(v) = it.next() {
+            <U as fmt::Display>::fmt(&v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, F, U> ser::Serialize for Mapped<'_, T, F>
+where
+    F: Fn(&T) -> U,
+    U: fmt::Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Formats only the elements for which `pred` returns `true`.
+#[derive(Clone, Copy)]
+pub struct Filtered<'a, T, P> {
+    items: &'a [T],
+    pred: P,
+}
+
+impl<'a, T, P> Filtered<'a, T, P>
+where
+    P: Fn(&T) -> bool,
+{
+    #[inline]
+    pub fn new(items: &'a [T], pred: P) -> Self {
+        Self { items, pred }
+    }
+}
+
+impl<T, P> fmt::Display for Filtered<'_, T, P>
+where
+    T: fmt::Display,
+    P: Fn(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.items.iter().filter(|v| (self.pred)(v));
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, P> ser::Serialize for Filtered<'_, T, P>
+where
+    T: fmt::Display,
+    P: Fn(&T) -> bool,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<T> crate::vec::CS<T> {
+    /// A view that formats each element through `f`.
+    #[inline]
+    pub fn map_display<F, U>(&self, f: F) -> Mapped<'_, T, F>
+    where
+        F: Fn(&T) -> U,
+        U: fmt::Display,
+    {
+        Mapped::new(&self.0, f)
+    }
+
+    /// A view that leaves out the elements for which `pred` returns `false`.
+    #[inline]
+    pub fn filter_display<P>(&self, pred: P) -> Filtered<'_, T, P>
+    where
+        P: Fn(&T) -> bool,
+    {
+        Filtered::new(&self.0, pred)
+    }
+}
+
+impl<T, const N: usize> crate::array::CS<T, N> {
+    /// A view that formats each element through `f`.
+    #[inline]
+    pub fn map_display<F, U>(&self, f: F) -> Mapped<'_, T, F>
+    where
+        F: Fn(&T) -> U,
+        U: fmt::Display,
+    {
+        Mapped::new(&self.0, f)
+    }
+
+    /// A view that leaves out the elements for which `pred` returns `false`.
+    #[inline]
+    pub fn filter_display<P>(&self, pred: P) -> Filtered<'_, T, P>
+    where
+        P: Fn(&T) -> bool,
+    {
+        Filtered::new(&self.0, pred)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filtered, Mapped};
+    use crate::{array, vec};
+
+    #[test]
+    fn map_display() {
+        let cs = vec::CS::new(vec![1.5f64, 2.25]);
+        assert_eq!(cs.map_display(|v| v * 2.0).to_string(), "3,4.5");
+        assert_eq!(
+            cs.map_display(|v| format!("{:.1}", v)).to_string(),
+            "1.5,2.2"
+        );
+
+        let cs = array::CS::new([1000u32, 2500]);
+        assert_eq!(cs.map_display(|v| v / 1000).to_string(), "1,2");
+
+        let empty: [u32; 0] = [];
+        assert_eq!(Mapped::new(&empty, |v| *v).to_string(), "");
+    }
+
+    #[test]
+    fn serialize() {
+        let cs = vec::CS::new(vec!["secret-token", "x"]);
+        let s = serde_json::to_string(&cs.map_display(|s| &s[..1]));
+        assert_eq!(s.unwrap(), r#""s,x""#);
+    }
+
+    #[test]
+    fn filter_display() {
+        let cs = vec::CS::new(vec!["a", "", "internal:b", "c"]);
+        let visible = cs.filter_display(|s| !s.is_empty() && !s.starts_with("internal:"));
+        assert_eq!(visible.to_string(), "a,c");
+        assert_eq!(cs.0.len(), 4);
+
+        let cs = array::CS::new([1, 2, 3, 4]);
+        assert_eq!(cs.filter_display(|v| v % 2 == 0).to_string(), "2,4");
+        assert_eq!(cs.filter_display(|_| false).to_string(), "");
+
+        let s = serde_json::to_string(&Filtered::new(&[1, 2, 3], |v| *v != 2));
+        assert_eq!(s.unwrap(), r#""1,3""#);
+    }
+}
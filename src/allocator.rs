@@ -0,0 +1,207 @@
+//! Comma separated lists stored in a caller provided allocator.
+//!
+//! `std::vec::Vec` cannot take a custom allocator on stable, so this module
+//! mirrors [`vec::CS`](crate::vec::CS) on top of the `allocator-api2` vector.
+//! Deserializing into a specific allocator goes through [`InAllocator`].
+//!
+//! ```rust
+//! use allocator_api2::alloc::Global;
+//! use serde::de::DeserializeSeed;
+//! use serde_cs::allocator::{InAllocator, CS};
+//!
+//! let mut de = serde_json::Deserializer::from_str(r#""1,2,3""#);
+//! let cs: CS<u32> = InAllocator::new(Global).deserialize(&mut de).unwrap();
+//! assert_eq!(cs.to_string(), "1,2,3");
+//! ```
+
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::vec::{self, Vec};
+
+use serde::de;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CS<T, A: Allocator = Global>(pub Vec<T, A>);
+
+list_wrapper! {
+    impl[T, A: Allocator] CS[T, A](Vec<T, A>):
+        Default, AsRef[T], From, Inner, InnerMut, Serialize
+}
+
+impl<T, A: Allocator> CS<T, A> {
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        Self(Vec::new_in(alloc))
+    }
+
+    /// Parses `s` into a list allocated in `alloc`.
+    pub fn parse_in(s: &str, alloc: A) -> Result<Self, T::Err>
+    where
+        T: FromStr,
+    {
+        let mut v = Vec::new_in(alloc);
+        for s in s.split(',').filter(|s| !s.is_empty()) {
+            v.push(s.parse()?);
+        }
+        Ok(Self(v))
+    }
+}
+
+impl<T: FromStr> FromStr for CS<T> {
+    type Err = T::Err;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_in(s, Global)
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for CS<T, A> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, A: Allocator> fmt::Display for CS<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+/// Deserializes a list into the wrapped allocator.
+pub struct InAllocator<T, A>(A, PhantomData<T>);
+
+impl<T, A: Allocator> InAllocator<T, A> {
+    #[inline]
+    pub fn new(alloc: A) -> Self {
+        Self(alloc, PhantomData)
+    }
+}
+
+impl<'de, T, A> de::DeserializeSeed<'de> for InAllocator<T, A>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    A: Allocator,
+{
+    type Value = CS<T, A>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, T, A> de::Visitor<'de> for InAllocator<T, A>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    A: Allocator,
+{
+    type Value = CS<T, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("comma separated list")
+    }
+
+    fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        CS::parse_in(values, self.0).map_err(de::Error::custom)
+    }
+}
+
+impl<'de, T> de::Deserialize<'de> for CS<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        de::DeserializeSeed::deserialize(InAllocator::new(Global), deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InAllocator, CS};
+
+    use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+    use serde::de::DeserializeSeed;
+
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+
+    struct Counting<'a>(&'a Cell<usize>);
+
+    unsafe impl Allocator for Counting<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let cs: Result<CS<u32>, _> = ",1,,2,".parse();
+        assert_eq!(cs.unwrap().0.as_slice(), [1, 2]);
+
+        let cs: Result<CS<u32>, _> = "1,a".parse();
+        assert!(cs.is_err());
+    }
+
+    #[test]
+    fn parse_in() {
+        let count = Cell::new(0);
+        let cs = CS::<u32, _>::parse_in("1,2,3", Counting(&count)).unwrap();
+        assert_eq!(cs.as_ref(), [1, 2, 3]);
+        assert!(count.get() > 0);
+
+        let count = Cell::new(0);
+        let cs = CS::<u32, _>::parse_in("", Counting(&count)).unwrap();
+        assert!(cs.0.is_empty());
+        assert_eq!(count.get(), 0);
+    }
+
+    #[test]
+    fn serde() {
+        let count = Cell::new(0);
+        let mut de = serde_json::Deserializer::from_str(r#""4,5""#);
+        let cs: CS<u32, _> = InAllocator::new(Counting(&count))
+            .deserialize(&mut de)
+            .unwrap();
+        assert_eq!(cs.as_ref(), [4, 5]);
+        assert!(count.get() > 0);
+
+        let cs: Result<CS<u32>, _> = serde_json::from_str(r#""4,5""#);
+        assert_eq!(cs.unwrap().0.as_slice(), [4, 5]);
+
+        let cs: CS<u32> = CS([1, 2].into_iter().collect());
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""1,2""#);
+    }
+}
@@ -1,34 +1,204 @@
+//! Lists of exactly `N` elements, stored in an array.
+//!
+//! By default, missing elements are filled with `T::default()` and extra ones
+//! are dropped. A [`Fill`] policy such as [`AtMost`] or [`Exact`] rejects
+//! such input instead, and [`Exact`] only needs elements to be `FromStr`:
+//!
+//! ```rust
+//! use serde_cs::array::{AtMost, ExactCS, RepeatLast, CS};
+//! use serde_cs::separator::Comma;
+//! use serde_cs::Error;
+//!
+//! let cs: CS<u32, 3> = "1".parse().unwrap();
+//! assert_eq!(cs.0, [1, 0, 0]);
+//!
+//! let cs: Result<CS<u32, 2, Comma, AtMost>, _> = "1,2,3".parse();
+//! assert!(matches!(cs, Err(Error::TooMany { max: 2 })));
+//!
+//! let cs: CS<u32, 3, Comma, RepeatLast> = "1,2".parse().unwrap();
+//! assert_eq!(cs.0, [1, 2, 2]);
+//!
+//! let cs: Result<ExactCS<u32, 3>, _> = "1".parse();
+//! assert!(matches!(cs, Err(Error::Arity { expected: 3, found: 1 })));
+//! ```
+//!
+//! [`Partial`] keeps track of how many elements were given instead of
+//! padding:
+//!
+//! ```rust
+//! use serde_cs::array::Partial;
+//!
+//! let cs: Partial<u32, 4> = serde_json::from_str(r#""0,7""#).unwrap();
+//! assert_eq!(cs.len(), 2);
+//! assert_eq!(cs.as_slice(), [0, 7]);
+//! assert_eq!(cs.to_string(), "0,7");
+//! ```
+//!
+//! Lists of integers can also be parsed at compile time:
+//!
+//! ```rust
+//! use serde_cs::array::CS;
+//!
+//! const PORTS: CS<u16, 2> = match CS::<u16, 2>::parse_const("80,443") {
+//!     Some(cs) => cs,
+//!     None => panic!("invalid port list"),
+//! };
+//! assert_eq!(PORTS.0, [80, 443]);
+//! ```
+
+use crate::separator::{write_sep, Char, Comma, Separator};
+use crate::vec;
+use crate::Error;
+
 use serde::de;
 use serde::ser;
 
+use std::convert::Infallible;
+use std::marker::PhantomData;
 use std::str::FromStr;
-use std::{array, fmt};
+use std::{array, fmt, iter, slice};
+
+/// How a parsed list is completed to `N` elements, or rejected.
+pub trait Fill<T> {
+    /// The error returned when an element fails to parse with `E`, or when
+    /// the list cannot be completed.
+    type Err<E>: From<E>;
+
+    /// Completes `v`, which holds the first elements of an input of `found`
+    /// elements, to exactly `N` elements.
+    fn fill<E, const N: usize>(v: Vec<T>, found: usize) -> Result<[T; N], Self::Err<E>>;
+}
+
+/// Missing elements are filled with `T::default()`, and extra ones dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pad;
+
+impl<T: Default> Fill<T> for Pad {
+    type Err<E> = E;
+
+    #[inline]
+    fn fill<E, const N: usize>(v: Vec<T>, _found: usize) -> Result<[T; N], E> {
+        let mut it = v.into_iter();
+        Ok(array::from_fn(|_| it.next().unwrap_or_default()))
+    }
+}
+
+/// Anything but exactly `N` elements fails with [`Error::Arity`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Exact;
+
+impl<T> Fill<T> for Exact {
+    type Err<E> = Error<E>;
+
+    #[inline]
+    fn fill<E, const N: usize>(v: Vec<T>, found: usize) -> Result<[T; N], Error<E>> {
+        let arity = Error::Arity { expected: N, found };
+        if found != N {
+            return Err(arity);
+        }
+        v.try_into().map_err(|_| arity)
+    }
+}
+
+/// Missing elements are filled with `T::default()` as with [`Pad`], but
+/// more than `N` fail with [`Error::TooMany`] instead of being dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AtMost;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CS<T, const N: usize>(pub [T; N]);
+impl<T: Default> Fill<T> for AtMost {
+    type Err<E> = Error<E>;
 
-impl<T: Default + Copy, const N: usize> Default for CS<T, N> {
+    #[inline]
+    fn fill<E, const N: usize>(v: Vec<T>, found: usize) -> Result<[T; N], Error<E>> {
+        if found > N {
+            return Err(Error::TooMany { max: N });
+        }
+        let mut it = v.into_iter();
+        Ok(array::from_fn(|_| it.next().unwrap_or_default()))
+    }
+}
+
+/// Missing elements repeat the last given one, and extra ones are dropped.
+/// An empty input fails with [`Error::Empty`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RepeatLast;
+
+impl<T: Clone> Fill<T> for RepeatLast {
+    type Err<E> = Error<E>;
+
+    fn fill<E, const N: usize>(mut v: Vec<T>, found: usize) -> Result<[T; N], Error<E>> {
+        if v.len() < N {
+            let last = v.last().cloned().ok_or(Error::Empty)?;
+            v.resize(N, last);
+        }
+        v.try_into()
+            .map_err(|_| Error::Arity { expected: N, found })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CS<T, const N: usize, S = Comma, P = Pad>(pub [T; N], pub PhantomData<(S, P)>);
+
+/// A list separated by the `char` given as `SEP`.
+pub type CSWith<T, const N: usize, const SEP: char> = CS<T, N, Char<SEP>>;
+
+/// A list that must have exactly `N` elements.
+pub type ExactCS<T, const N: usize, S = Comma> = CS<T, N, S, Exact>;
+
+impl<T: Default, const N: usize, S, P> Default for CS<T, N, S, P> {
     #[inline]
     fn default() -> Self {
-        Self([T::default(); N])
+        Self::with_separator(array::from_fn(|_| T::default()))
     }
 }
 
-impl<T, const N: usize> AsRef<[T]> for CS<T, N> {
+impl<T, const N: usize, S, P> AsRef<[T]> for CS<T, N, S, P> {
     #[inline]
     fn as_ref(&self) -> &[T] {
         &self.0
     }
 }
 
-impl<T, const N: usize> From<[T; N]> for CS<T, N> {
+impl<T, const N: usize, S, P> From<[T; N]> for CS<T, N, S, P> {
     #[inline]
     fn from(v: [T; N]) -> Self {
-        Self(v)
+        Self::with_separator(v)
+    }
+}
+
+impl<T, const N: usize, S, P> TryFrom<vec::CS<T, S>> for CS<T, N, S, P> {
+    type Error = Error<Infallible>;
+
+    /// Fails with [`Error::Arity`] unless `cs` has exactly `N` elements.
+    fn try_from(cs: vec::CS<T, S>) -> Result<Self, Self::Error> {
+        let found = cs.0.len();
+        <[T; N]>::try_from(cs.into_inner())
+            .map(Self::with_separator)
+            .map_err(|_| Error::Arity { expected: N, found })
+    }
+}
+
+impl<T, const N: usize, S, P> From<CS<T, N, S, P>> for vec::CS<T, S> {
+    #[inline]
+    fn from(cs: CS<T, N, S, P>) -> Self {
+        Self::with_separator(cs.0.into())
     }
 }
 
 impl<T, const N: usize> CS<T, N> {
+    #[inline]
+    pub const fn new(v: [T; N]) -> Self {
+        Self(v, PhantomData)
+    }
+}
+
+impl<T, const N: usize, S, P> CS<T, N, S, P> {
+    /// Like [`CS::new`], for lists with a separator other than [`Comma`].
+    #[inline]
+    pub const fn with_separator(v: [T; N]) -> Self {
+        Self(v, PhantomData)
+    }
+
     #[inline]
     pub fn into_inner(self) -> [T; N] {
         self.0
@@ -43,25 +213,40 @@ impl<T, const N: usize> CS<T, N> {
     pub fn to_inner_mut(&mut self) -> &mut [T; N] {
         &mut self.0
     }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
 }
 
-impl<T: FromStr + Default + Copy, const N: usize> FromStr for CS<T, N> {
-    type Err = T::Err;
+impl<T, const N: usize, S, P> FromStr for CS<T, N, S, P>
+where
+    T: FromStr,
+    S: Separator,
+    P: Fill<T>,
+{
+    type Err = P::Err<T::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut arr = Self::default();
-        let it_mut = IntoIterator::into_iter(&mut arr.0);
-
-        let split = s.split(',').filter(|s| !s.is_empty());
+        let mut split = S::split(s).filter(|s| !s.is_empty());
 
-        for (entry, s) in it_mut.zip(split) {
-            *entry = s.parse()?;
+        let mut v = Vec::with_capacity(N);
+        for s in split.by_ref().take(N) {
+            v.push(S::CASE.apply(s).parse()?);
         }
-        Ok(arr)
+
+        let found = v.len() + split.count();
+        P::fill(v, found).map(Self::from)
     }
 }
 
-impl<T, const N: usize> IntoIterator for CS<T, N> {
+impl<T, const N: usize, S, P> IntoIterator for CS<T, N, S, P> {
     type Item = T;
     type IntoIter = array::IntoIter<T, N>;
 
@@ -70,49 +255,74 @@ impl<T, const N: usize> IntoIterator for CS<T, N> {
     }
 }
 
-impl<T: fmt::Display, const N: usize> fmt::Display for CS<T, N> {
+impl<'a, T, const N: usize, S, P> IntoIterator for &'a CS<T, N, S, P> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize, S, P> IntoIterator for &'a mut CS<T, N, S, P> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator, P> fmt::Display for CS<T, N, S, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut it = IntoIterator::into_iter(&self.0);
         if let Some(v) = it.next() {
-            <T as fmt::Display>::fmt(v, f)?;
+            S::CASE.fmt(v, f)?;
         }
 
         for v in it {
-            write!(f, ",{}", v)?
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
         }
 
         Ok(())
     }
 }
 
-impl<T: fmt::Display, const N: usize> ser::Serialize for CS<T, N> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl<T: fmt::Display, const N: usize, S: Separator, P> ser::Serialize for CS<T, N, S, P> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
     where
-        S: ser::Serializer,
+        Ser: ser::Serializer,
     {
         serializer.serialize_str(&self.to_string())
     }
 }
 
-impl<'de, T, const N: usize> de::Deserialize<'de> for CS<T, N>
+impl<'de, T, const N: usize, S, P> de::Deserialize<'de> for CS<T, N, S, P>
 where
-    T: FromStr + Default + Copy,
-    T::Err: fmt::Display,
+    T: FromStr,
+    S: Separator,
+    P: Fill<T>,
+    P::Err<T::Err>: fmt::Display,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        use std::marker::PhantomData;
-
-        struct CsVisitor<T, const N: usize>(PhantomData<T>);
+        struct CsVisitor<T, const N: usize, S, P>(PhantomData<(T, S, P)>);
 
-        impl<'de, T, const N: usize> de::Visitor<'de> for CsVisitor<T, N>
+        impl<'de, T, const N: usize, S, P> de::Visitor<'de> for CsVisitor<T, N, S, P>
         where
-            T: FromStr + Default + Copy,
-            T::Err: fmt::Display,
+            T: FromStr,
+            S: Separator,
+            P: Fill<T>,
+            P::Err<T::Err>: fmt::Display,
         {
-            type Value = CS<T, N>;
+            type Value = CS<T, N, S, P>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("comma separeted list")
@@ -130,6 +340,309 @@ where
     }
 }
 
+/// Whether `sep` occurs in `b` at `i`.
+const fn sep_at(b: &[u8], i: usize, sep: &[u8]) -> bool {
+    if i + sep.len() > b.len() {
+        return false;
+    }
+    let mut j = 0;
+    while j < sep.len() {
+        if b[i + j] != sep[j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+macro_rules! const_parse {
+    ($($t:ty)*) => {$(
+        impl<const N: usize, S: Separator, P> CS<$t, N, S, P> {
+            /// Parses exactly `N` integers in a `const` context, splitting on
+            /// `S::SEP` and skipping empty segments. Returns `None` on any
+            /// invalid or out-of-range element, or on a different number of
+            /// elements.
+            pub const fn parse_const(s: &str) -> Option<Self> {
+                let b = s.as_bytes();
+                let sep = S::SEP.as_bytes();
+                if sep.is_empty() {
+                    return None;
+                }
+
+                let mut arr = [0; N];
+                let mut n = 0;
+                let mut i = 0;
+                while i <= b.len() {
+                    let start = i;
+                    while i < b.len() && !sep_at(b, i, sep) {
+                        i += 1;
+                    }
+
+                    if i > start {
+                        if n == N {
+                            return None;
+                        }
+                        arr[n] = match Self::parse_segment(b, start, i) {
+                            Some(v) => v,
+                            None => return None,
+                        };
+                        n += 1;
+                    }
+                    i += sep.len();
+                }
+
+                if n == N {
+                    Some(Self::with_separator(arr))
+                } else {
+                    None
+                }
+            }
+
+            const fn parse_segment(b: &[u8], mut i: usize, end: usize) -> Option<$t> {
+                let negative = b[i] == b'-';
+                if negative && <$t>::MIN == 0 {
+                    return None;
+                }
+                if negative || b[i] == b'+' {
+                    i += 1;
+                }
+                if i == end {
+                    return None;
+                }
+
+                let mut v: $t = 0;
+                while i < end {
+                    let d = b[i].wrapping_sub(b'0');
+                    if d > 9 {
+                        return None;
+                    }
+                    v = match v.checked_mul(10) {
+                        Some(v) => v,
+                        None => return None,
+                    };
+                    let next = if negative {
+                        v.checked_sub(d as $t)
+                    } else {
+                        v.checked_add(d as $t)
+                    };
+                    v = match next {
+                        Some(v) => v,
+                        None => return None,
+                    };
+                    i += 1;
+                }
+                Some(v)
+            }
+        }
+    )*};
+}
+
+const_parse!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Up to `N` elements in an array, together with how many were parsed.
+///
+/// Only the filled prefix is compared, formatted and iterated; the remaining
+/// slots hold `T::default()` and are never observable. More than `N` elements
+/// fail with [`Error::TooMany`].
+pub struct Partial<T, const N: usize, S = Comma>([T; N], usize, PhantomData<S>);
+
+impl<T: fmt::Debug, const N: usize, S> fmt::Debug for Partial<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Partial").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T: Clone, const N: usize, S> Clone for Partial<T, N, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1, PhantomData)
+    }
+}
+
+impl<T: PartialEq, const N: usize, S> PartialEq for Partial<T, N, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize, S> Eq for Partial<T, N, S> {}
+
+impl<T: Default, const N: usize, S> Default for Partial<T, N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self(array::from_fn(|_| T::default()), 0, PhantomData)
+    }
+}
+
+impl<T, const N: usize, S> AsRef<[T]> for Partial<T, N, S> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize, S> From<[T; N]> for Partial<T, N, S> {
+    /// A full list.
+    #[inline]
+    fn from(v: [T; N]) -> Self {
+        Self(v, N, PhantomData)
+    }
+}
+
+impl<T, const N: usize, S> Partial<T, N, S> {
+    pub const CAPACITY: usize = N;
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.1
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.1 == 0
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.1 == N
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.0[..self.1]
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0[..self.1]
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Appends `v`, or hands it back if the list is full.
+    pub fn try_push(&mut self, v: T) -> Result<(), T> {
+        match self.0.get_mut(self.1) {
+            Some(slot) => {
+                *slot = v;
+                self.1 += 1;
+                Ok(())
+            }
+            None => Err(v),
+        }
+    }
+
+    /// The backing array and the number of filled slots.
+    #[inline]
+    pub fn into_parts(self) -> ([T; N], usize) {
+        (self.0, self.1)
+    }
+}
+
+impl<T: FromStr + Default, const N: usize, S: Separator> FromStr for Partial<T, N, S> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut partial = Self::default();
+        for s in S::split(s).filter(|s| !s.is_empty()) {
+            let v = S::CASE.apply(s).parse()?;
+            partial.try_push(v).map_err(|_| Error::TooMany { max: N })?;
+        }
+        Ok(partial)
+    }
+}
+
+impl<T, const N: usize, S> IntoIterator for Partial<T, N, S> {
+    type Item = T;
+    type IntoIter = iter::Take<array::IntoIter<T, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().take(self.1)
+    }
+}
+
+impl<'a, T, const N: usize, S> IntoIterator for &'a Partial<T, N, S> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator> fmt::Display for Partial<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator> ser::Serialize for Partial<T, N, S> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, T, const N: usize, S> de::Deserialize<'de> for Partial<T, N, S>
+where
+    T: FromStr + Default,
+    T::Err: fmt::Display,
+    S: Separator,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct PartialVisitor<T, const N: usize, S>(PhantomData<(T, S)>);
+
+        impl<'de, T, const N: usize, S> de::Visitor<'de> for PartialVisitor<T, N, S>
+        where
+            T: FromStr + Default,
+            T::Err: fmt::Display,
+            S: Separator,
+        {
+            type Value = Partial<T, N, S>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    formatter,
+                    "list of at most {} elements separated by {:?}",
+                    N,
+                    S::SEP
+                )
+            }
+
+            fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                values.parse().map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(PartialVisitor(PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::CS;
@@ -137,7 +650,7 @@ mod tests {
 
     fn assert_ok_from_str<const N: usize>(s: &str, expected: [u32; N]) {
         let cs: Result<CsTest<N>, _> = s.parse();
-        assert!(matches!(cs, Ok(v) if v == CS(expected)))
+        assert!(matches!(cs, Ok(v) if v == CS::new(expected)))
     }
 
     fn assert_err_from_str<const N: usize>(s: &str) {
@@ -165,7 +678,7 @@ mod tests {
     }
 
     fn assert_to_string<const N: usize>(values: [u32; N], expected: &str) {
-        let cs = CS(values).to_string();
+        let cs = CS::new(values).to_string();
         assert_eq!(cs, expected);
     }
 
@@ -179,7 +692,7 @@ mod tests {
 
     fn assert_ok_des<const N: usize>(s: &str, expected: [u32; N]) {
         let cs: Result<CsTest<N>, _> = serde_json::from_str(s);
-        assert!(matches!(cs, Ok(v) if v == CS(expected)))
+        assert!(matches!(cs, Ok(v) if v == CS::new(expected)))
     }
 
     fn assert_err_des<const N: usize>(s: &str) {
@@ -207,7 +720,7 @@ mod tests {
     }
 
     fn assert_ser<const N: usize>(values: [u32; N], expected: &str) {
-        let cs = serde_json::to_string(&CS(values));
+        let cs = serde_json::to_string(&CS::new(values));
         assert!(matches!(cs, Ok(v) if v == expected))
     }
 
@@ -218,4 +731,297 @@ mod tests {
         assert_ser([1, 2], r#""1,2""#);
         assert_ser([1, 2, 3, 4, 5], r#""1,2,3,4,5""#);
     }
+
+    #[test]
+    fn separator() {
+        use crate::separator::Pipe;
+
+        let cs: Result<CS<u32, 2, Pipe>, _> = "|1||2|".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        assert_eq!(CS::<_, 2, Pipe>::with_separator([1, 2]).to_string(), "1|2");
+    }
+
+    #[test]
+    fn multi_char_separator() {
+        use crate::separator::CommaSpace;
+
+        let cs: Result<CS<u32, 3, CommaSpace>, _> = "1, 2, 3".parse();
+        assert_eq!(cs.unwrap().0, [1, 2, 3]);
+        assert_eq!(
+            CS::<_, 2, CommaSpace>::with_separator([1, 2]).to_string(),
+            "1, 2"
+        );
+    }
+
+    #[test]
+    fn trailing_separator() {
+        use crate::separator::{Pipe, Trailing};
+
+        assert_eq!(CS::<u32, 0, Trailing<Pipe>>::default().to_string(), "");
+        assert_eq!(CS::<_, 2, Trailing<Pipe>>::from([1, 2]).to_string(), "1|2|");
+    }
+
+    #[test]
+    fn case_policy() {
+        use crate::separator::{Comma, Upper};
+
+        let cs = CS::<_, 2, Upper<Comma>>::from(['a', 'b']);
+        assert_eq!(cs.to_string(), "A,B");
+    }
+
+    #[test]
+    fn alternate_display() {
+        use crate::separator::Semicolon;
+
+        let cs = CS::<_, 3, Semicolon>::from([1, 2, 3]);
+        assert_eq!(format!("{:#}", cs), "1; 2; 3");
+        assert_eq!(cs.to_string(), "1;2;3");
+    }
+
+    #[test]
+    fn unicode_separator() {
+        use super::CSWith;
+        let cs: Result<CSWith<u32, 2, '·'>, _> = "1·2".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        assert_eq!(CSWith::<_, 2, '·'>::from([3, 4]).to_string(), "3·4");
+    }
+
+    #[test]
+    fn char_separator() {
+        use super::CSWith;
+
+        let cs: Result<CSWith<u32, 2, ' '>, _> = " 1  2".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        assert_eq!(
+            CSWith::<_, 2, '/'>::with_separator([1, 2]).to_string(),
+            "1/2"
+        );
+    }
+
+    #[test]
+    fn pad_error() {
+        use std::num::ParseIntError;
+
+        let err: Option<ParseIntError> = "1,x".parse::<CsTest<2>>().err();
+        assert_eq!(err, "x".parse::<u32>().err());
+    }
+
+    #[test]
+    fn exact_length() {
+        use super::ExactCS;
+        use crate::Error;
+
+        let cs: Result<ExactCS<u32, 2>, _> = ",1,,2,".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+
+        let cs: Result<ExactCS<u32, 2>, _> = "1".parse();
+        assert_eq!(
+            cs.unwrap_err(),
+            Error::Arity {
+                expected: 2,
+                found: 1
+            }
+        );
+        let cs: Result<ExactCS<u32, 2>, _> = "1,2,3".parse();
+        assert_eq!(
+            cs.unwrap_err(),
+            Error::Arity {
+                expected: 2,
+                found: 3
+            }
+        );
+        let cs: Result<ExactCS<u32, 0>, _> = "".parse();
+        assert!(cs.is_ok());
+
+        let cs: Result<ExactCS<u32, 3>, _> = serde_json::from_str(r#""1,2""#);
+        assert!(cs.is_err());
+    }
+
+    #[test]
+    fn overflow() {
+        use super::AtMost;
+        use crate::separator::Comma;
+        use crate::Error;
+
+        assert_ok_from_str("1,2,3", [1, 2]);
+
+        let cs: Result<CS<u32, 2, Comma, AtMost>, _> = "1".parse();
+        assert_eq!(cs.unwrap().0, [1, 0]);
+        let cs: Result<CS<u32, 2, Comma, AtMost>, _> = ",1,,2,".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        let cs: Result<CS<u32, 2, Comma, AtMost>, _> = "1,2,x".parse();
+        assert_eq!(cs.unwrap_err(), Error::TooMany { max: 2 });
+    }
+
+    #[test]
+    fn partial() {
+        use super::Partial;
+        use crate::separator::{Pipe, Trailing};
+        use crate::Error;
+
+        let cs: Result<Partial<u32, 3>, _> = ",0,,2".parse();
+        let v = cs.as_ref().unwrap();
+        assert_eq!(v.as_slice(), [0, 2]);
+        assert!(!v.is_full());
+        let cs: Result<Partial<u32, 3>, _> = "".parse();
+        assert!(cs.as_ref().unwrap().is_empty());
+        let cs: Result<Partial<u32, 2>, _> = "1,2,3".parse();
+        assert_eq!(cs.unwrap_err(), Error::TooMany { max: 2 });
+
+        let mut cs = Partial::<String, 2, Trailing<Pipe>>::default();
+        assert!(cs.try_push("a".into()).is_ok());
+        assert_eq!(cs.to_string(), "a|");
+        assert!(cs.try_push("b".into()).is_ok());
+        assert_eq!(cs.try_push("c".into()).unwrap_err(), "c");
+        assert_eq!(cs.into_iter().collect::<Vec<_>>(), ["a", "b"]);
+
+        let a: Partial<u32, 3> = "1".parse().unwrap_or_default();
+        let b: Partial<u32, 3> = "1,0".parse().unwrap_or_default();
+        assert_ne!(a, b);
+        assert_eq!(serde_json::to_string(&a).ok().as_deref(), Some(r#""1""#));
+    }
+
+    #[test]
+    fn non_copy_elements() {
+        use super::ExactCS;
+        use std::net::Ipv4Addr;
+
+        let cs: Result<CS<String, 3>, _> = "a,b".parse();
+        assert_eq!(cs.unwrap().0, ["a", "b", ""]);
+
+        let cs: Result<ExactCS<Ipv4Addr, 2>, _> = serde_json::from_str(r#""10.0.0.1,10.0.0.2""#);
+        assert_eq!(cs.unwrap().0[0], Ipv4Addr::new(10, 0, 0, 1));
+    }
+
+    #[test]
+    fn repeat_last() {
+        use super::RepeatLast;
+        use crate::separator::Comma;
+        use crate::Error;
+
+        type Repeat = CS<String, 3, Comma, RepeatLast>;
+
+        let cs: Result<Repeat, _> = "a".parse();
+        assert_eq!(cs.unwrap().0, ["a", "a", "a"]);
+        let cs: Result<Repeat, _> = "a,b,c,d".parse();
+        assert_eq!(cs.unwrap().0, ["a", "b", "c"]);
+        let cs: Result<Repeat, _> = ",,".parse();
+        assert_eq!(cs.unwrap_err(), Error::Empty);
+
+        let cs: Result<CS<u32, 0, Comma, RepeatLast>, _> = "".parse();
+        assert!(cs.is_ok());
+    }
+
+    #[test]
+    fn parse_const() {
+        use crate::separator::Semicolon;
+
+        const PORTS: CS<u16, 3> = match CS::<u16, 3>::parse_const("80,,443,8080") {
+            Some(cs) => cs,
+            None => panic!(),
+        };
+        assert_eq!(PORTS.0, [80, 443, 8080]);
+
+        assert_eq!(
+            CS::<i8, 2>::parse_const("-128,+127").map(|cs| cs.0),
+            Some([-128, 127])
+        );
+        assert_eq!(
+            CS::<u8, 2, Semicolon>::parse_const(";1;;2;").map(|cs| cs.0),
+            Some([1, 2])
+        );
+        assert_eq!(CS::<u32, 0>::parse_const("").map(|cs| cs.0), Some([]));
+
+        assert!(CS::<u8, 1>::parse_const("256").is_none());
+        assert!(CS::<i8, 1>::parse_const("-129").is_none());
+        assert!(CS::<u8, 1>::parse_const("-1").is_none());
+        assert!(CS::<u8, 1>::parse_const("+").is_none());
+        assert!(CS::<u8, 1>::parse_const("1a").is_none());
+        assert!(CS::<u8, 2>::parse_const("1").is_none());
+        assert!(CS::<u8, 1>::parse_const("1,2").is_none());
+    }
+
+    #[test]
+    fn ordering_and_hashing() {
+        use crate::separator::Pipe;
+        use std::collections::{BTreeSet, HashMap};
+
+        let a = CS::new([1, 2]);
+        let b = a;
+        assert_eq!(a, b);
+        assert!(CS::new([1, 2]) < CS::new([1, 3]));
+
+        let set: BTreeSet<_> = [CS::new([2, 0]), CS::new([1, 9])].into();
+        assert_eq!(set.first(), Some(&CS::new([1, 9])));
+
+        let mut map = HashMap::new();
+        map.insert(CS::<_, 2, Pipe>::from(["a", "b"]), 1);
+        assert_eq!(map.get(&CS::from(["a", "b"])), Some(&1));
+    }
+
+    #[test]
+    fn vec_conversion() {
+        use crate::separator::Pipe;
+        use crate::{vec, Error};
+
+        let cs = CS::<_, 2, Pipe>::try_from(vec::CS::with_separator(vec![1, 2]));
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        let cs = CS::<_, 3>::try_from(vec::CS::new(vec![1, 2]));
+        assert_eq!(
+            cs.unwrap_err(),
+            Error::Arity {
+                expected: 3,
+                found: 2
+            }
+        );
+
+        assert_eq!(vec::CS::from(CS::new([1, 2])), vec::CS::new(vec![1, 2]));
+        assert_eq!(vec::CS::from([1, 2]), vec::CS::new(vec![1, 2]));
+    }
+
+    #[test]
+    fn iteration() {
+        let mut cs = CS::new([1, 2, 3]);
+        for v in &mut cs {
+            *v *= 10;
+        }
+        cs.iter_mut().for_each(|v| *v += 1);
+
+        let mut sum = 0;
+        for v in &cs {
+            sum += v;
+        }
+        assert_eq!(sum, 63);
+        assert_eq!(cs.iter().max(), Some(&31));
+        assert_eq!(cs.into_iter().collect::<Vec<_>>(), [11, 21, 31]);
+    }
+
+    #[test]
+    fn owned_elements() {
+        use super::{ExactCS, Partial};
+        use crate::separator::{Semicolon, Spaced};
+
+        type Names = CS<String, 3, Spaced<Semicolon>>;
+
+        let cs: Result<Names, _> = serde_json::from_str(r#""ann; bob""#);
+        assert_eq!(cs.as_ref().unwrap().0, ["ann", "bob", ""]);
+
+        let cs = cs.map(|mut cs| {
+            cs.0[2].push_str("cy");
+            cs
+        });
+        let s = cs.as_ref().map(|cs| cs.to_string());
+        assert_eq!(s.unwrap(), "ann; bob; cy");
+
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""ann; bob; cy""#);
+
+        let cs: Result<ExactCS<String, 2>, _> = serde_json::from_str(r#""x,y""#);
+        let cloned = cs.as_ref().map(Clone::clone);
+        assert_eq!(cloned.unwrap().to_string(), "x,y");
+
+        let cs: Result<Partial<String, 3>, _> = serde_json::from_str(r#""p,q""#);
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""p,q""#);
+    }
 }
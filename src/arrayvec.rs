@@ -0,0 +1,130 @@
+//! Lists of at most `N` elements, stored inline in an [`ArrayVec`].
+//!
+//! Requires the `arrayvec` feature.
+//!
+//! Unlike [`array::CS`](crate::array::CS) the number of elements may vary, and
+//! unlike [`vec::CS`](crate::vec::CS) it is bounded: input with more than `N`
+//! elements fails with [`Error::TooMany`] instead of being truncated.
+//!
+//! ```rust
+//! use serde_cs::arrayvec::CS;
+//! use serde_cs::Error;
+//!
+//! let cs: CS<u32, 3> = serde_json::from_str(r#""1,2""#).unwrap();
+//! assert_eq!(cs.0.as_slice(), [1, 2]);
+//! assert_eq!(cs.to_string(), "1,2");
+//!
+//! let cs: Result<CS<u32, 3>, _> = "1,2,3,4".parse();
+//! assert!(matches!(cs, Err(Error::TooMany { max: 3 })));
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use arrayvec::ArrayVec;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct CS<T, const N: usize, S = Comma>(pub ArrayVec<T, N>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, const N: usize, S] CS[T, N, S](ArrayVec<T, N>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| {
+            write!(f, "list of at most {} elements separated by {:?}", N, S::SEP)
+        })
+}
+
+impl<T: FromStr, const N: usize, S: Separator> FromStr for CS<T, N, S> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut v = ArrayVec::new();
+        for s in S::split(s).filter(|s| !s.is_empty()) {
+            let item = S::CASE.apply(s).parse()?;
+            v.try_push(item).map_err(|_| Error::TooMany { max: N })?;
+        }
+        Ok(Self::from(v))
+    }
+}
+
+impl<T, const N: usize, S> IntoIterator for CS<T, N, S> {
+    type Item = T;
+    type IntoIter = arrayvec::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator> fmt::Display for CS<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::{Pipe, Trailing};
+    use crate::Error;
+
+    use arrayvec::ArrayVec;
+
+    type CsTest = CS<u32, 3>;
+
+    fn assert_ok_from_str(s: &str, expected: &[u32]) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v.as_slice() == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", &[]);
+        assert_ok_from_str(",1,,2,", &[1, 2]);
+        assert_ok_from_str("1,2,3,,", &[1, 2, 3]);
+
+        assert_eq!(
+            "1,2,3,4".parse::<CsTest>().unwrap_err(),
+            Error::TooMany { max: 3 }
+        );
+        assert!(matches!("1,a".parse::<CsTest>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(CsTest::default().to_string(), "");
+        let cs = CsTest::from(ArrayVec::from([1, 2, 3]));
+        assert_eq!(format!("{:#}", cs), "1, 2, 3");
+
+        let cs = CS::<_, 2, Trailing<Pipe>>::from(ArrayVec::from([1, 2]));
+        assert_eq!(cs.to_string(), "1|2|");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String, 2, Pipe>, _> = serde_json::from_str(r#""a|b""#);
+        assert_eq!(cs.unwrap().0.as_slice(), ["a", "b"]);
+        let cs: Result<CS<String, 2, Pipe>, _> = serde_json::from_str(r#""a|b|c""#);
+        assert!(cs.is_err());
+
+        let s = serde_json::to_string(&CsTest::from(ArrayVec::from([1, 2, 3])));
+        assert_eq!(s.unwrap(), r#""1,2,3""#);
+    }
+}
@@ -0,0 +1,176 @@
+//! Conversions between comma separated lists and Apache Arrow arrays.
+//!
+//! A single list maps to a flat array, and a column of lists (one per record)
+//! to a [`ListArray`]. Arrow nulls have no comma separated form, so converting
+//! back fails with [`Error::Null`] when one is found.
+//!
+//! ```rust
+//! use arrow_array::{Array, Int64Array, StringArray};
+//! use serde_cs::vec::CS;
+//!
+//! let ids: CS<i64> = "1,2,3".parse().unwrap();
+//! let array = Int64Array::from(ids);
+//! assert_eq!(array.len(), 3);
+//!
+//! let ids = CS::try_from(&array).unwrap();
+//! assert_eq!(ids.to_string(), "1,2,3");
+//!
+//! let tags = StringArray::from(CS::new(vec!["a", "b"]));
+//! assert_eq!(tags.value(1), "b");
+//! ```
+
+use crate::vec::CS;
+use crate::Error;
+
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::{ArrowPrimitiveType, ListArray, PrimitiveArray, StringArray};
+
+use std::convert::Infallible;
+
+impl<T: ArrowPrimitiveType> From<CS<T::Native>> for PrimitiveArray<T> {
+    #[inline]
+    fn from(cs: CS<T::Native>) -> Self {
+        Self::from_iter_values(cs.0)
+    }
+}
+
+impl<S: AsRef<str>> From<CS<S>> for StringArray {
+    #[inline]
+    fn from(cs: CS<S>) -> Self {
+        Self::from_iter_values(cs.0)
+    }
+}
+
+impl<T: ArrowPrimitiveType> TryFrom<&PrimitiveArray<T>> for CS<T::Native> {
+    type Error = Error<Infallible>;
+
+    fn try_from(array: &PrimitiveArray<T>) -> Result<Self, Self::Error> {
+        array
+            .iter()
+            .map(|v| v.ok_or(Error::Null))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
+
+impl TryFrom<&StringArray> for CS<String> {
+    type Error = Error<Infallible>;
+
+    fn try_from(array: &StringArray) -> Result<Self, Self::Error> {
+        array
+            .iter()
+            .map(|v| v.map(String::from).ok_or(Error::Null))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
+
+/// Builds a list array holding one list per record.
+pub fn to_list_array<T, I>(records: I) -> ListArray
+where
+    T: ArrowPrimitiveType,
+    I: IntoIterator<Item = CS<T::Native>>,
+{
+    ListArray::from_iter_primitive::<T, _, _>(
+        records
+            .into_iter()
+            .map(|cs| Some(cs.0.into_iter().map(Some))),
+    )
+}
+
+/// Builds a list array of strings holding one list per record.
+pub fn to_string_list_array<S, I>(records: I) -> ListArray
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = CS<S>>,
+{
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for cs in records {
+        for v in cs.0 {
+            builder.values().append_value(v);
+        }
+        builder.append(true);
+    }
+    builder.finish()
+}
+
+/// Splits a list array back into one list per record.
+///
+/// # Panics
+///
+/// Panics if the values of `array` are not of type `T`.
+pub fn from_list_array<T: ArrowPrimitiveType>(
+    array: &ListArray,
+) -> Result<Vec<CS<T::Native>>, Error<Infallible>> {
+    array
+        .iter()
+        .map(|list| CS::try_from(list.ok_or(Error::Null)?.as_primitive::<T>()))
+        .collect()
+}
+
+/// Splits a list array of strings back into one list per record.
+///
+/// # Panics
+///
+/// Panics if the values of `array` are not strings.
+pub fn from_string_list_array(array: &ListArray) -> Result<Vec<CS<String>>, Error<Infallible>> {
+    array
+        .iter()
+        .map(|list| CS::try_from(list.ok_or(Error::Null)?.as_string::<i32>()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_list_array, from_string_list_array, to_list_array, to_string_list_array};
+    use crate::vec::CS;
+    use crate::Error;
+
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{Array, Int64Array, ListArray, StringArray};
+
+    #[test]
+    fn primitive() {
+        let array = Int64Array::from(CS::new(vec![1, -2]));
+        assert_eq!(array.values().as_ref(), [1, -2]);
+        assert_eq!(CS::try_from(&array), Ok(CS::new(vec![1, -2])));
+
+        let array = Int64Array::from(vec![Some(1), None]);
+        assert_eq!(CS::try_from(&array), Err(Error::Null));
+    }
+
+    #[test]
+    fn string() {
+        let array = StringArray::from(CS::new(vec!["a", "b"]));
+        assert_eq!(
+            CS::try_from(&array),
+            Ok(CS::new(vec!["a".to_string(), "b".to_string()]))
+        );
+
+        let array = StringArray::from(vec![None, Some("a")]);
+        assert_eq!(CS::try_from(&array), Err(Error::Null));
+    }
+
+    #[test]
+    fn list() {
+        let records = vec![CS::new(vec![1, 2]), CS::new(vec![]), CS::new(vec![3])];
+        let array = to_list_array::<Int32Type, _>(records.clone());
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.value_length(0), 2);
+        assert_eq!(from_list_array::<Int32Type>(&array), Ok(records));
+
+        let array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![Some(1)]), None]);
+        assert_eq!(from_list_array::<Int64Type>(&array), Err(Error::Null));
+    }
+
+    #[test]
+    fn string_list() {
+        let array = to_string_list_array(vec![CS::new(vec!["a", "b"]), CS::new(vec!["c"])]);
+        let records = from_string_list_array(&array).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].to_string(), "a,b");
+        assert_eq!(records[1].to_string(), "c");
+    }
+}
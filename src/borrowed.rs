@@ -0,0 +1,137 @@
+//! String lists whose elements are slices of the input, without any copying.
+//!
+//! Deserializing requires a deserializer that lends its input, such as
+//! `serde_json::from_str`; strings it has to unescape or buffer are rejected.
+//! [`cow::CS`](crate::cow::CS) falls back to owned elements instead.
+//!
+//! ```rust
+//! use serde_cs::borrowed::CS;
+//!
+//! let input = String::from(r#""rust,serde,,json""#);
+//! let cs: CS<'_> = serde_json::from_str(&input).unwrap();
+//! assert_eq!(cs.0, ["rust", "serde", "json"]);
+//! assert_eq!(cs.to_string(), "rust,serde,json");
+//!
+//! let cs: Result<CS<'_>, _> = serde_json::from_str(r#""a\tb""#);
+//! assert!(cs.is_err());
+//! ```
+//!
+//! Segments are never copied, so a [`Lower`](crate::separator::Lower) or
+//! [`Upper`](crate::separator::Upper) separator only affects formatting.
+
+use crate::separator::{write_sep, Comma, Separator};
+
+use serde::de;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::vec;
+
+pub struct CS<'a, S = Comma>(pub Vec<&'a str>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl['a, S] CS['a, S](Vec<&'a str>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[&'a str], From, Inner, InnerMut, Serialize
+}
+
+impl<'a, S: Separator> From<&'a str> for CS<'a, S> {
+    /// Splits `s`, skipping empty segments.
+    fn from(s: &'a str) -> Self {
+        Self::from(S::split(s).filter(|s| !s.is_empty()).collect::<Vec<_>>())
+    }
+}
+
+impl<'a, S> IntoIterator for CS<'a, S> {
+    type Item = &'a str;
+    type IntoIter = vec::IntoIter<&'a str>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<S: Separator> fmt::Display for CS<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de: 'a, 'a, S: Separator> de::Deserialize<'de> for CS<'a, S> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct BorrowedVisitor<'a, S>(PhantomData<(&'a str, S)>);
+
+        impl<'de: 'a, 'a, S: Separator> de::Visitor<'de> for BorrowedVisitor<'a, S> {
+            type Value = CS<'a, S>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "borrowed list separated by {:?}", S::SEP)
+            }
+
+            fn visit_borrowed_str<E>(self, values: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(CS::from(values))
+            }
+        }
+
+        deserializer.deserialize_str(BorrowedVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::{Pipe, Semicolon, Trim, Upper};
+
+    #[test]
+    fn from_str() {
+        assert_eq!(CS::<Pipe>::from("|a||b|").0, ["a", "b"]);
+        assert_eq!(CS::<Trim<Pipe>>::from(" a | b ").0, ["a", "b"]);
+        assert!(CS::<Pipe>::from("").0.is_empty());
+    }
+
+    #[test]
+    fn deserialize() {
+        let s = String::from(r#""x;;y""#);
+        let cs: Result<CS<'_, Semicolon>, _> = serde_json::from_str(&s);
+        assert_eq!(cs.unwrap().0, ["x", "y"]);
+
+        #[derive(serde::Deserialize)]
+        struct Query<'a> {
+            #[serde(borrow)]
+            tags: CS<'a>,
+        }
+
+        let q: Result<Query<'_>, _> = serde_json::from_str(r#"{"tags":"a,b"}"#);
+        assert_eq!(q.unwrap().tags.0, ["a", "b"]);
+
+        assert!(serde_json::from_str::<CS<'_>>(r#""a\tb""#).is_err());
+        assert!(serde_json::from_str::<CS<'_>>("1").is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        let cs = CS::<Upper<Semicolon>>::from(vec!["a", "b"]);
+        assert_eq!(format!("{:#}", cs), "A; B");
+        let s = serde_json::to_string(&cs);
+        assert_eq!(s.unwrap(), r#""A;B""#);
+    }
+}
@@ -0,0 +1,178 @@
+//! Lists whose number of elements must lie within `MIN..=MAX`.
+//!
+//! ```rust
+//! use serde_cs::bounded::BoundedCS;
+//! use serde_cs::Error;
+//!
+//! type Tags = BoundedCS<String, 1, 3>;
+//!
+//! let tags: Tags = serde_json::from_str(r#""a,b""#).unwrap();
+//! assert_eq!(tags.to_string(), "a,b");
+//!
+//! assert!(matches!("".parse::<Tags>(), Err(Error::TooFew { min: 1 })));
+//! assert!(matches!("a,b,c,d".parse::<Tags>(), Err(Error::TooMany { max: 3 })));
+//! ```
+//!
+//! Bounds with `MIN > MAX` are rejected at compile time:
+//!
+//! ```rust,compile_fail
+//! let tags = "a".parse::<serde_cs::bounded::BoundedCS<String, 3, 1>>();
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::vec;
+use crate::Error;
+
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A list of between `MIN` and `MAX` elements, both inclusive.
+pub struct BoundedCS<T, const MIN: usize, const MAX: usize, S = Comma>(Vec<T>, PhantomData<S>);
+
+list_wrapper! {
+    impl[T, const MIN: usize, const MAX: usize, S] BoundedCS[T, MIN, MAX, S](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, AsRef[T], Inner, Serialize,
+        Deserialize([S: Separator] |f| {
+            write!(f, "list of {} to {} elements separated by {:?}", MIN, MAX, S::SEP)
+        })
+}
+
+struct Bounds<const MIN: usize, const MAX: usize>;
+
+impl<const MIN: usize, const MAX: usize> Bounds<MIN, MAX> {
+    const VALID: () = assert!(MIN <= MAX, "BoundedCS needs MIN <= MAX");
+}
+
+fn check<E, const MIN: usize, const MAX: usize>(len: usize) -> Result<(), Error<E>> {
+    let () = Bounds::<MIN, MAX>::VALID;
+
+    if len < MIN {
+        Err(Error::TooFew { min: MIN })
+    } else if len > MAX {
+        Err(Error::TooMany { max: MAX })
+    } else {
+        Ok(())
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize, S> TryFrom<Vec<T>> for BoundedCS<T, MIN, MAX, S> {
+    type Error = Error<Infallible>;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        check::<_, MIN, MAX>(v.len())?;
+        Ok(Self(v, PhantomData))
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize, S> From<BoundedCS<T, MIN, MAX, S>> for vec::CS<T, S> {
+    #[inline]
+    fn from(cs: BoundedCS<T, MIN, MAX, S>) -> Self {
+        Self::with_separator(cs.0)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize, S> BoundedCS<T, MIN, MAX, S> {
+    /// Returns `None` unless `v` has between `MIN` and `MAX` elements.
+    #[inline]
+    pub fn new(v: Vec<T>) -> Option<Self> {
+        Self::try_from(v).ok()
+    }
+}
+
+impl<T: FromStr, const MIN: usize, const MAX: usize, S: Separator> FromStr
+    for BoundedCS<T, MIN, MAX, S>
+{
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let v = s.parse::<vec::CS<T, S>>()?.into_inner();
+        check::<_, MIN, MAX>(v.len())?;
+        Ok(Self(v, PhantomData))
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize, S> IntoIterator for BoundedCS<T, MIN, MAX, S> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const MIN: usize, const MAX: usize, S: Separator> fmt::Display
+    for BoundedCS<T, MIN, MAX, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedCS;
+    use crate::separator::Pipe;
+    use crate::{vec, Error};
+
+    type CsTest = BoundedCS<u32, 1, 3>;
+
+    fn assert_ok_from_str(s: &str, expected: &[u32]) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(v) if v.as_ref() == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("1", &[1]);
+        assert_ok_from_str(",1,,2,3,", &[1, 2, 3]);
+
+        assert_eq!(
+            ",,".parse::<CsTest>().unwrap_err(),
+            Error::TooFew { min: 1 }
+        );
+        assert_eq!(
+            "1,2,3,4".parse::<CsTest>().unwrap_err(),
+            Error::TooMany { max: 3 }
+        );
+        assert!(matches!("1,x".parse::<CsTest>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn try_from() {
+        assert!(CsTest::new(vec![]).is_none());
+        assert_eq!(
+            CsTest::try_from(vec![1, 2, 3, 4]).unwrap_err(),
+            Error::TooMany { max: 3 }
+        );
+
+        let cs = BoundedCS::<_, 0, 2, Pipe>::try_from(vec![1, 2]);
+        assert_eq!(cs.map(|cs| cs.to_string()), Ok("1|2".to_string()));
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<BoundedCS<String, 2, 2, Pipe>, _> = serde_json::from_str(r#""a|b""#);
+        assert_eq!(cs.as_ref().unwrap().to_inner(), &["a", "b"]);
+        let v = cs.map(vec::CS::from);
+        assert_eq!(v.unwrap().to_string(), "a|b");
+
+        let cs: Result<CsTest, _> = serde_json::from_str(r#""""#);
+        assert!(cs.unwrap_err().to_string().contains("fewer than 1"));
+    }
+}
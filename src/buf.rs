@@ -0,0 +1,59 @@
+use crate::separator::Separator;
+
+use bytes::{Bytes, BytesMut};
+
+use std::fmt::{self, Write};
+
+fn write_display(buf: &mut BytesMut, v: &impl fmt::Display) {
+    write!(buf, "{}", v).expect("a Display implementation returned an error unexpectedly");
+}
+
+impl<T: fmt::Display, S: Separator> crate::vec::CS<T, S> {
+    /// Appends the joined form to `buf`.
+    #[inline]
+    pub fn write_to(&self, buf: &mut BytesMut) {
+        write_display(buf, self)
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut buf = BytesMut::new();
+        self.write_to(&mut buf);
+        buf.freeze()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator, P> crate::array::CS<T, N, S, P> {
+    /// Appends the joined form to `buf`.
+    #[inline]
+    pub fn write_to(&self, buf: &mut BytesMut) {
+        write_display(buf, self)
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut buf = BytesMut::new();
+        self.write_to(&mut buf);
+        buf.freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{array, vec};
+    use bytes::BytesMut;
+
+    #[test]
+    fn to_bytes() {
+        assert_eq!(vec::CS::<u32>::new(vec![]).to_bytes(), "");
+        assert_eq!(vec::CS::new(vec![1, 2, 3]).to_bytes(), "1,2,3");
+        assert_eq!(array::CS::new([1, 2]).to_bytes(), "1,2");
+    }
+
+    #[test]
+    fn write_to() {
+        let mut buf = BytesMut::from("ids=");
+        vec::CS::new(vec![1, 2]).write_to(&mut buf);
+        buf.extend_from_slice(b";");
+        array::CS::new([3]).write_to(&mut buf);
+        assert_eq!(buf, "ids=1,2;3");
+    }
+}
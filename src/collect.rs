@@ -0,0 +1,121 @@
+//! Lists parsed into any collection that can be built from and iterated over
+//! its elements.
+//!
+//! ```rust
+//! use serde_cs::collect::CsCollect;
+//!
+//! use std::collections::LinkedList;
+//!
+//! let cs: CsCollect<LinkedList<u32>, u32> = serde_json::from_str(r#""1,2,3""#).unwrap();
+//! assert_eq!(cs.0.back(), Some(&3));
+//! assert_eq!(cs.to_string(), "1,2,3");
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A collection `C` of `T`, formatted in the collection's iteration order.
+pub struct CsCollect<C, T, S = Comma>(pub C, pub PhantomData<(T, S)>);
+
+list_wrapper! {
+    impl[C, T, S] CsCollect[C, T, S](C, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<C, T, S> FromStr for CsCollect<C, T, S>
+where
+    C: FromIterator<T>,
+    T: FromStr,
+    S: Separator,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        S::split(s)
+            .filter(|s| !s.is_empty())
+            .map(|s| S::CASE.apply(s).parse())
+            .collect::<Result<C, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<C: IntoIterator, T, S> IntoIterator for CsCollect<C, T, S> {
+    type Item = C::Item;
+    type IntoIter = C::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<C, T, S> fmt::Display for CsCollect<C, T, S>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a T>,
+    T: fmt::Display,
+    S: Separator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = (&self.0).into_iter();
+        let Some(first) = it.next() else {
+            return Ok(());
+        };
+        S::CASE.fmt(first, f)?;
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CsCollect;
+    use crate::separator::Semicolon;
+
+    use std::collections::{BTreeSet, VecDeque};
+
+    #[test]
+    fn from_str() {
+        let cs: Result<CsCollect<Vec<u32>, u32>, _> = ",1,,2".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+
+        let cs: Result<CsCollect<BTreeSet<u32>, u32>, _> = "3,1,3".parse();
+        assert_eq!(cs.unwrap().0, BTreeSet::from([1, 3]));
+
+        let cs: Result<CsCollect<String, char>, _> = "a,b,c".parse();
+        assert_eq!(cs.unwrap().0, "abc");
+
+        assert!("1,x".parse::<CsCollect<Vec<u32>, u32>>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let cs = CsCollect::<_, u32>::from(BTreeSet::from([2, 1]));
+        assert_eq!(cs.to_string(), "1,2");
+        assert_eq!(CsCollect::<Vec<u32>, u32>::default().to_string(), "");
+
+        let cs = CsCollect::<_, _, Semicolon>::from(VecDeque::from(["a", "b"]));
+        assert_eq!(format!("{:#}", cs), "a; b");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CsCollect<VecDeque<String>, String, Semicolon>, _> =
+            serde_json::from_str(r#""x;y""#);
+        assert_eq!(cs.as_ref().unwrap().0, ["x", "y"]);
+
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""x;y""#);
+    }
+}
@@ -0,0 +1,62 @@
+//! Lists of [`CompactString`] elements, which store short tokens inline.
+//!
+//! Requires the `compact_str` feature.
+//!
+//! ```rust
+//! use serde_cs::compact::CS;
+//!
+//! let cs: CS = serde_json::from_str(r#""red,green,blue""#).unwrap();
+//! assert!(cs.0.iter().all(|s| !s.is_heap_allocated()));
+//! assert_eq!(cs.to_compact_string(), "red,green,blue");
+//! ```
+
+use crate::separator::{Comma, Separator};
+use crate::vec;
+
+use compact_str::{CompactString, ToCompactString};
+
+use std::fmt;
+
+/// A list of inline-stored strings.
+pub type CS<S = Comma> = vec::CS<CompactString, S>;
+
+impl<T: fmt::Display, S: Separator> vec::CS<T, S> {
+    /// Formats the list into a [`CompactString`], without allocating when
+    /// the result is short.
+    #[inline]
+    pub fn to_compact_string(&self) -> CompactString {
+        ToCompactString::to_compact_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::Pipe;
+    use crate::vec;
+
+    use compact_str::CompactString;
+
+    #[test]
+    fn from_str() {
+        let cs: Result<CS<Pipe>, _> = "|a||bb|".parse();
+        assert_eq!(cs.unwrap().0, ["a", "bb"]);
+
+        let long = "x".repeat(64);
+        let cs: Result<CS, _> = format!("a,{}", long).parse();
+        let v = cs.unwrap().0;
+        assert!(v[1].is_heap_allocated());
+        assert_eq!(v[1], long);
+    }
+
+    #[test]
+    fn to_string() {
+        let cs = CS::<Pipe>::from(vec![CompactString::from("a"), "b".into()]);
+        assert_eq!(cs.to_compact_string(), "a|b");
+        assert!(!cs.to_compact_string().is_heap_allocated());
+        assert_eq!(vec::CS::new(vec![1, 2]).to_compact_string(), "1,2");
+
+        let s = serde_json::to_string(&cs);
+        assert_eq!(s.unwrap(), r#""a|b""#);
+    }
+}
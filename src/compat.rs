@@ -0,0 +1,138 @@
+//! Drop-in replacement for `serde_with::StringWithSeparator`.
+//!
+//! The wire behavior is kept as is: an empty string is an empty list, but
+//! otherwise every segment is parsed, including empty ones, so `"a,,b"` has
+//! three elements and `","` fails for types that reject `""`.
+//!
+//! ```rust
+//! use serde_cs::compat::{CommaSeparator, SpaceSeparator, StringWithSeparator};
+//!
+//! let cs: StringWithSeparator<CommaSeparator, String> = serde_json::from_str(r#""a,,b""#).unwrap();
+//! assert_eq!(cs.into_inner(), vec!["a", "", "b"]);
+//!
+//! let cs = StringWithSeparator::<SpaceSeparator, _>::from(vec![1, 2]);
+//! assert_eq!(serde_json::to_string(&cs).unwrap(), r#""1 2""#);
+//! ```
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// Same shape as `serde_with::Separator`, so existing implementations only
+/// need their import changed.
+pub trait Separator {
+    fn separator() -> &'static str;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CommaSeparator;
+
+impl Separator for CommaSeparator {
+    #[inline]
+    fn separator() -> &'static str {
+        ","
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SpaceSeparator;
+
+impl Separator for SpaceSeparator {
+    #[inline]
+    fn separator() -> &'static str {
+        " "
+    }
+}
+
+pub struct StringWithSeparator<Sep, T>(pub Vec<T>, PhantomData<Sep>);
+
+list_wrapper! {
+    impl[Sep, T] StringWithSeparator[Sep, T](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize(|f| f.write_str("a string"))
+}
+
+impl<Sep: Separator, T: FromStr> FromStr for StringWithSeparator<Sep, T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        s.split(Sep::separator())
+            .map(T::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<Sep, T> IntoIterator for StringWithSeparator<Sep, T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<Sep: Separator, T: fmt::Display> fmt::Display for StringWithSeparator<Sep, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, "{}{}", Sep::separator(), v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommaSeparator, SpaceSeparator, StringWithSeparator};
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let cs: Result<StringWithSeparator<CommaSeparator, String>, _> = s.parse();
+        assert!(matches!(cs, Ok(StringWithSeparator(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",", vec!["", ""]);
+        assert_ok_from_str("a,,b", vec!["a", "", "b"]);
+        assert_ok_from_str(" a , b", vec![" a ", " b"]);
+
+        let cs: Result<StringWithSeparator<CommaSeparator, u32>, _> = "1,,2".parse();
+        assert!(cs.is_err());
+        let cs: Result<StringWithSeparator<SpaceSeparator, u32>, _> = "1 2".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+    }
+
+    #[test]
+    fn to_string() {
+        let cs = StringWithSeparator::<CommaSeparator, &str>::from(vec![]);
+        assert_eq!(cs.to_string(), "");
+        let cs = StringWithSeparator::<CommaSeparator, _>::from(vec!["a", "", "b"]);
+        assert_eq!(cs.to_string(), "a,,b");
+        let cs = StringWithSeparator::<SpaceSeparator, _>::from(vec![""]);
+        assert_eq!(cs.to_string(), "");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<StringWithSeparator<CommaSeparator, u32>, _> =
+            serde_json::from_str(r#""1,2""#);
+        assert_eq!(cs.unwrap().0, [1, 2]);
+
+        let cs: Result<StringWithSeparator<CommaSeparator, u32>, _> = serde_json::from_str("1");
+        assert!(cs.is_err());
+
+        let cs = StringWithSeparator::<CommaSeparator, _>::from(vec![1, 2]);
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""1,2""#);
+    }
+}
@@ -0,0 +1,177 @@
+//! String lists that borrow their elements from the input when possible.
+//!
+//! When the deserializer lends its input, as `serde_json::from_str` does for
+//! strings without escapes, every element is a [`Cow::Borrowed`] slice of it;
+//! otherwise the elements are owned.
+//!
+//! ```rust
+//! use serde_cs::cow::CS;
+//!
+//! use std::borrow::Cow;
+//!
+//! let cs: CS<'_> = serde_json::from_str(r#""rust,serde,,json""#).unwrap();
+//! assert!(cs.0.iter().all(|tag| matches!(tag, Cow::Borrowed(_))));
+//! assert_eq!(cs.to_string(), "rust,serde,json");
+//!
+//! let cs: CS<'_> = serde_json::from_str(r#""a\tb,c""#).unwrap();
+//! assert!(matches!(&cs.0[0], Cow::Owned(s) if s == "a\tb"));
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+
+use serde::de;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+use std::vec;
+
+pub struct CS<'a, S = Comma>(pub Vec<Cow<'a, str>>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl['a, S] CS['a, S](Vec<Cow<'a, str>>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[Cow<'a, str>], From, Inner, InnerMut, Serialize
+}
+
+impl<'a, S: Separator> From<&'a str> for CS<'a, S> {
+    /// Splits `s` without copying any element.
+    fn from(s: &'a str) -> Self {
+        Self::from(split::<S>(s).collect::<Vec<_>>())
+    }
+}
+
+impl<'a, S> CS<'a, S> {
+    /// Copies every borrowed element, detaching the list from its input.
+    pub fn into_owned(self) -> CS<'static, S> {
+        CS::from(
+            self.0
+                .into_iter()
+                .map(|s| Cow::Owned(s.into_owned()))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+fn split<S: Separator>(s: &str) -> impl Iterator<Item = Cow<'_, str>> {
+    S::split(s)
+        .filter(|s| !s.is_empty())
+        .map(|s| S::CASE.apply(s))
+}
+
+impl<'a, S> IntoIterator for CS<'a, S> {
+    type Item = Cow<'a, str>;
+    type IntoIter = vec::IntoIter<Cow<'a, str>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<S: Separator> fmt::Display for CS<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de, S: Separator> de::Deserialize<'de> for CS<'de, S> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct CowVisitor<S>(PhantomData<S>);
+
+        impl<'de, S: Separator> de::Visitor<'de> for CowVisitor<S> {
+            type Value = CS<'de, S>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "list separated by {:?}", S::SEP)
+            }
+
+            fn visit_borrowed_str<E>(self, values: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(CS::from(values))
+            }
+
+            fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(CS::from(values).into_owned())
+            }
+        }
+
+        deserializer.deserialize_str(CowVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::{Comma, Lower, Pipe, Semicolon};
+
+    use serde::de::value::{self, StringDeserializer};
+    use serde::Deserialize;
+
+    use std::borrow::Cow;
+
+    fn is_borrowed(cs: &CS<'_, impl Sized>) -> bool {
+        cs.0.iter().all(|s| matches!(s, Cow::Borrowed(_)))
+    }
+
+    #[test]
+    fn from_str() {
+        let cs = CS::<Pipe>::from("|a||b|");
+        assert_eq!(cs.0, ["a", "b"]);
+        assert!(is_borrowed(&cs));
+
+        let cs = CS::<Lower<Pipe>>::from("a|B");
+        assert!(cs.0 == ["a", "b"] && !is_borrowed(&cs));
+
+        assert!(CS::<Pipe>::from("").0.is_empty());
+    }
+
+    #[test]
+    fn deserialize() {
+        let cs: Result<CS<'_>, _> = serde_json::from_str(r#""a,,b""#);
+        let v = cs.as_ref().unwrap();
+        assert_eq!(v.0, ["a", "b"]);
+        assert!(is_borrowed(v));
+
+        let cs: Result<CS<'_>, _> = serde_json::from_str(r#""a\u002cb,c""#);
+        let v = cs.as_ref().unwrap();
+        assert_eq!(v.0, ["a", "b", "c"]);
+        assert!(!is_borrowed(v));
+
+        let de = StringDeserializer::<value::Error>::new("x,y".to_string());
+        let cs = CS::<Comma>::deserialize(de);
+        let v = cs.as_ref().unwrap();
+        assert_eq!(v.0, ["x", "y"]);
+        assert!(!is_borrowed(v));
+
+        assert!(serde_json::from_str::<CS<'_>>("1").is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        let cs = CS::<Semicolon>::from(vec![Cow::Borrowed("a"), Cow::Owned("b".into())]);
+        assert_eq!(format!("{:#}", cs), "a; b");
+        let s = serde_json::to_string(&cs);
+        assert_eq!(s.unwrap(), r#""a;b""#);
+    }
+}
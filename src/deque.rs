@@ -0,0 +1,127 @@
+//! Lists parsed into a [`VecDeque`], for queue-like use of the elements.
+//!
+//! ```rust
+//! use serde_cs::deque::CS;
+//!
+//! let mut cs: CS<u32> = serde_json::from_str(r#""2,3""#).unwrap();
+//! cs.0.push_front(1);
+//! assert_eq!(cs.0.pop_back(), Some(3));
+//! assert_eq!(cs.to_string(), "1,2");
+//! ```
+//!
+//! Converting from and to [`vec::CS`] reuses the allocation.
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::vec;
+
+use std::collections::{vec_deque, VecDeque};
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct CS<T, S = Comma>(pub VecDeque<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](VecDeque<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T, S> From<vec::CS<T, S>> for CS<T, S> {
+    #[inline]
+    fn from(cs: vec::CS<T, S>) -> Self {
+        Self::from(VecDeque::from(cs.into_inner()))
+    }
+}
+
+impl<T, S> From<CS<T, S>> for vec::CS<T, S> {
+    #[inline]
+    fn from(cs: CS<T, S>) -> Self {
+        Self::with_separator(Vec::from(cs.0))
+    }
+}
+
+impl<T: FromStr, S: Separator> FromStr for CS<T, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<vec::CS<T, S>>().map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for CS<T, S> {
+    type Item = T;
+    type IntoIter = vec_deque::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for CS<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::{Pipe, Trailing};
+    use crate::vec;
+
+    use std::collections::VecDeque;
+
+    type CsTest = CS<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",1,,2,", vec![1, 2]);
+        assert!("1,a".parse::<CsTest>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let mut cs = CsTest::from(VecDeque::from([2, 3]));
+        cs.0.push_front(1);
+        assert_eq!(cs.to_string(), "1,2,3");
+        cs.0.rotate_left(1);
+        assert_eq!(format!("{:#}", cs), "2, 3, 1");
+
+        let cs = CS::<_, Trailing<Pipe>>::from(VecDeque::from([1]));
+        assert_eq!(cs.to_string(), "1|");
+    }
+
+    #[test]
+    fn vec_conversion() {
+        let cs = CS::from(vec::CS::new(vec![1, 2]));
+        assert_eq!(cs.0, [1, 2]);
+        assert_eq!(vec::CS::from(cs), vec::CS::new(vec![1, 2]));
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String, Pipe>, _> = serde_json::from_str(r#""a|b""#);
+        assert_eq!(cs.unwrap().0, ["a", "b"]);
+
+        let s = serde_json::to_string(&CsTest::from(VecDeque::from([1, 2])));
+        assert_eq!(s.unwrap(), r#""1,2""#);
+    }
+}
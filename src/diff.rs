@@ -0,0 +1,127 @@
+use crate::vec::CS;
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Elements added and removed between two lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsDiff<T> {
+    pub added: Vec<T>,
+    pub removed: Vec<T>,
+}
+
+impl<T> Default for CsDiff<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+}
+
+impl<T> CsDiff<T> {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+fn missing_from<T: Eq + Hash + Clone>(from: &[T], other: &[T]) -> Vec<T> {
+    let other: HashSet<&T> = other.iter().collect();
+    let mut seen = HashSet::new();
+    from.iter()
+        .filter(|v| !other.contains(v) && seen.insert(*v))
+        .cloned()
+        .collect()
+}
+
+impl<T, S> CS<T, S> {
+    /// Compares as sets: `added` holds the elements only found in `other`, and
+    /// `removed` those only found in `self`, each in order of first appearance.
+    pub fn diff(&self, other: &Self) -> CsDiff<T>
+    where
+        T: Eq + Hash + Clone,
+    {
+        CsDiff {
+            added: missing_from(&other.0, &self.0),
+            removed: missing_from(&self.0, &other.0),
+        }
+    }
+
+    /// Compares position by position, pairing each differing element with its index.
+    pub fn diff_positional(&self, other: &Self) -> CsDiff<(usize, T)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut diff = CsDiff::default();
+        let len = self.0.len().max(other.0.len());
+
+        for i in 0..len {
+            let (old, new) = (self.0.get(i), other.0.get(i));
+            if old == new {
+                continue;
+            }
+            if let Some(v) = old {
+                diff.removed.push((i, v.clone()));
+            }
+            if let Some(v) = new {
+                diff.added.push((i, v.clone()));
+            }
+        }
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CsDiff;
+    use crate::vec::CS;
+
+    fn assert_diff(old: Vec<u32>, new: Vec<u32>, added: Vec<u32>, removed: Vec<u32>) {
+        let diff = CS::new(old).diff(&CS::new(new));
+        assert_eq!(diff, CsDiff { added, removed });
+    }
+
+    #[test]
+    fn diff() {
+        assert_diff(vec![], vec![], vec![], vec![]);
+        assert_diff(vec![1, 2], vec![2, 1], vec![], vec![]);
+        assert_diff(vec![1, 2], vec![2, 3], vec![3], vec![1]);
+        assert_diff(vec![1, 1, 2], vec![3, 4, 3], vec![3, 4], vec![1, 2]);
+        assert_diff(vec![1, 2, 2], vec![1, 2], vec![], vec![]);
+    }
+
+    fn assert_diff_positional(
+        old: Vec<u32>,
+        new: Vec<u32>,
+        added: Vec<(usize, u32)>,
+        removed: Vec<(usize, u32)>,
+    ) {
+        let diff = CS::new(old).diff_positional(&CS::new(new));
+        assert_eq!(diff, CsDiff { added, removed });
+    }
+
+    #[test]
+    fn diff_positional() {
+        assert_diff_positional(vec![], vec![], vec![], vec![]);
+        assert_diff_positional(vec![1, 2], vec![1, 2], vec![], vec![]);
+        assert_diff_positional(
+            vec![1, 2],
+            vec![2, 1],
+            vec![(0, 2), (1, 1)],
+            vec![(0, 1), (1, 2)],
+        );
+        assert_diff_positional(vec![1], vec![1, 5], vec![(1, 5)], vec![]);
+        assert_diff_positional(vec![1, 5], vec![1], vec![], vec![(1, 5)]);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(CS::new(vec![1, 2]).diff(&CS::new(vec![2, 1])).is_empty());
+        assert!(!CS::new(vec![1, 2])
+            .diff_positional(&CS::new(vec![2, 1]))
+            .is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+//! Comma separated lists that also accept bytes in a legacy single-byte encoding.
+//!
+//! Strings are taken as they are; byte input (through `visit_bytes`) is
+//! decoded with the [`Charset`] before splitting.
+//!
+//! ```rust
+//! use serde::de::value::{BytesDeserializer, Error};
+//! use serde::Deserialize;
+//! use serde_cs::encoding::CS;
+//!
+//! let de = BytesDeserializer::<Error>::new(b"caf\xe9,\x80");
+//! let cs: CS<String> = CS::deserialize(de).unwrap();
+//! assert_eq!(cs.into_inner(), vec!["café", "€"]);
+//! ```
+
+use crate::Error;
+
+use serde::de;
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// Decodes byte input, returning `None` for malformed sequences.
+pub trait Charset {
+    fn decode(bytes: &[u8]) -> Option<Cow<'_, str>>;
+}
+
+/// Windows-1252, which is also what most "Latin-1" input actually is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Windows1252;
+
+impl Charset for Windows1252 {
+    fn decode(bytes: &[u8]) -> Option<Cow<'_, str>> {
+        encoding_rs::WINDOWS_1252.decode_without_bom_handling_and_without_replacement(bytes)
+    }
+}
+
+/// ISO-8859-1, mapping every byte to the code point of the same value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Latin1;
+
+impl Charset for Latin1 {
+    #[inline]
+    fn decode(bytes: &[u8]) -> Option<Cow<'_, str>> {
+        Some(encoding_rs::mem::decode_latin1(bytes))
+    }
+}
+
+pub struct CS<T, C = Windows1252>(pub Vec<T>, PhantomData<C>);
+
+list_wrapper! {
+    impl[T, C] CS[T, C](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize
+}
+
+impl<T, C> CS<T, C> {
+    /// Decodes `bytes` with `C` and parses the result.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error<T::Err>>
+    where
+        T: FromStr,
+        C: Charset,
+    {
+        let s = C::decode(bytes).ok_or(Error::InvalidEncoding)?;
+        Ok(s.parse()?)
+    }
+}
+
+impl<T: FromStr, C> FromStr for CS<T, C> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(T::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, C> IntoIterator for CS<T, C> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, C> fmt::Display for CS<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de, T, C> de::Deserialize<'de> for CS<T, C>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    C: Charset,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct CsVisitor<T, C>(PhantomData<(T, C)>);
+
+        impl<'de, T, C> de::Visitor<'de> for CsVisitor<T, C>
+        where
+            T: FromStr,
+            T::Err: fmt::Display,
+            C: Charset,
+        {
+            type Value = CS<T, C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("comma separated list as a string or encoded bytes")
+            }
+
+            fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                values.parse().map_err(de::Error::custom)
+            }
+
+            fn visit_bytes<E>(self, values: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                CS::from_bytes(values).map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CsVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Charset, Latin1, CS};
+    use crate::Error;
+
+    use serde::de::value::{self, BytesDeserializer};
+    use serde::Deserialize;
+
+    use std::borrow::Cow;
+
+    struct Ascii;
+
+    impl Charset for Ascii {
+        fn decode(bytes: &[u8]) -> Option<Cow<'_, str>> {
+            bytes.is_ascii().then(|| String::from_utf8_lossy(bytes))
+        }
+    }
+
+    #[test]
+    fn from_bytes() {
+        let cs = CS::<String>::from_bytes(b",na\xefve,,\x93q\x94,");
+        assert_eq!(cs.unwrap().0, ["naïve", "\u{201c}q\u{201d}"]);
+
+        let cs = CS::<String, Latin1>::from_bytes(b"\x93,\xff");
+        assert_eq!(cs.unwrap().0, ["\u{93}", "ÿ"]);
+
+        let cs = CS::<String, Ascii>::from_bytes(b"a,\xe9");
+        assert_eq!(cs.unwrap_err(), Error::InvalidEncoding);
+        let cs = CS::<u32>::from_bytes(b"1,\xe9");
+        assert!(matches!(cs, Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn serde() {
+        let de = BytesDeserializer::<value::Error>::new(b"1,2");
+        let cs = CS::<u32>::deserialize(de);
+        assert_eq!(cs.unwrap().0, [1, 2]);
+
+        let cs: Result<CS<String>, _> = serde_json::from_str(r#""é,ü""#);
+        assert_eq!(cs.unwrap().0, ["é", "ü"]);
+
+        let s = serde_json::to_string(&CS::<_>::from(vec!["é"]));
+        assert_eq!(s.unwrap(), r#""é""#);
+    }
+}
@@ -0,0 +1,102 @@
+use core::fmt;
+
+/// Error returned when a comma separated list cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error<E> {
+    /// An element failed to parse.
+    Parse(E),
+    /// A quoted element was not closed.
+    UnterminatedQuote,
+    /// The input ended with an unescaped backslash.
+    TrailingEscape,
+    /// An element lacked its required `+` or `-` prefix.
+    MissingPrefix,
+    /// An element was empty where a value was required.
+    Empty,
+    /// An element contained no known operator.
+    MissingOperator,
+    /// An element appeared more than once.
+    Duplicate,
+    /// The input was not valid UTF-8.
+    InvalidUtf8,
+    /// An escape sequence was malformed.
+    InvalidEscape,
+    /// An element lacked the delimiter between its parts.
+    MissingDelimiter,
+    /// An index was malformed or out of range.
+    InvalidIndex,
+    /// A repeat count was malformed.
+    InvalidCount,
+    /// A range ended before it started.
+    InvalidRange,
+    /// A numeric element fell outside `min..=max`.
+    OutOfRange { min: i128, max: i128 },
+    /// A null slot where a value was expected.
+    Null,
+    /// A point did not have exactly two coordinates.
+    InvalidPoint,
+    /// An interleaved list had an odd number of elements.
+    Unpaired,
+    /// The input was not valid in the expected character encoding.
+    InvalidEncoding,
+    /// A quote appeared inside an unquoted element or after a closing quote.
+    StrayQuote,
+    /// The rows of a matrix had different lengths.
+    Ragged,
+    /// A bounded list had more than `max` elements.
+    TooMany { max: usize },
+    /// A bounded list had fewer than `min` elements.
+    TooFew { min: usize },
+    /// A fixed-length record had the wrong number of elements.
+    Arity { expected: usize, found: usize },
+}
+
+impl<E> From<E> for Error<E> {
+    #[inline]
+    fn from(e: E) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => e.fmt(f),
+            Self::UnterminatedQuote => f.write_str("unterminated quote"),
+            Self::TrailingEscape => f.write_str("trailing backslash"),
+            Self::MissingPrefix => f.write_str("missing `+` or `-` prefix"),
+            Self::Empty => f.write_str("empty element"),
+            Self::MissingOperator => f.write_str("missing operator"),
+            Self::Duplicate => f.write_str("duplicate element"),
+            Self::InvalidUtf8 => f.write_str("invalid UTF-8"),
+            Self::InvalidEscape => f.write_str("invalid escape sequence"),
+            Self::MissingDelimiter => f.write_str("missing delimiter"),
+            Self::InvalidIndex => f.write_str("invalid index"),
+            Self::InvalidCount => f.write_str("invalid repeat count"),
+            Self::InvalidRange => f.write_str("range start is greater than its end"),
+            Self::OutOfRange { min, max } => write!(f, "value out of range {}..={}", min, max),
+            Self::Null => f.write_str("unexpected null value"),
+            Self::InvalidPoint => f.write_str("point must have exactly two coordinates"),
+            Self::Unpaired => f.write_str("element without a pair"),
+            Self::InvalidEncoding => f.write_str("invalid byte sequence for the encoding"),
+            Self::StrayQuote => f.write_str("unexpected quote"),
+            Self::Ragged => f.write_str("rows of unequal length"),
+            Self::TooMany { max } => write!(f, "more than {} elements", max),
+            Self::TooFew { min } => write!(f, "fewer than {} elements", min),
+            Self::Arity { expected, found } => {
+                write!(f, "expected {} elements, found {}", expected, found)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Lists whose elements may contain the separator, escaped with a backslash.
+//!
+//! When parsing, a backslash makes the next character literal; when
+//! formatting, backslashes and separators inside elements are escaped.
+//!
+//! ```rust
+//! use serde_cs::escaped::CS;
+//!
+//! let cs: CS<String> = serde_json::from_str(r#""a\\,b,c\\\\""#).unwrap();
+//! assert_eq!(cs.to_inner(), &["a,b", r"c\"]);
+//! assert_eq!(cs.to_string(), r"a\,b,c\\");
+//! ```
+//!
+//! Empty segments are skipped as in [`vec::CS`](crate::vec::CS), so empty
+//! elements do not survive a round trip: `["a", "", "b"]` is written as
+//! `"a,,b"` and parsed back as `["a", "b"]`. With a
+//! [`Spaced`](crate::separator::Spaced) separator, leading whitespace of an
+//! element is escaped so that it is not taken for the space after a
+//! separator.
+//!
+//! Segments are found by matching `S::SEP` exactly, so custom splitting such
+//! as [`Whitespace`](crate::separator::Whitespace)'s does not apply. An empty
+//! `S::SEP` is rejected at compile time:
+//!
+//! ```rust,compile_fail
+//! struct Empty;
+//!
+//! impl serde_cs::separator::Separator for Empty {
+//!     const SEP: &'static str = "";
+//! }
+//!
+//! let cs = "a".parse::<serde_cs::escaped::CS<String, Empty>>();
+//! ```
+
+use crate::separator::{write_sep, Comma, NonEmpty, Separator};
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+pub struct CS<T, S = Comma>(pub Vec<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by unescaped {:?}", S::SEP))
+}
+
+fn split<E, S: Separator>(s: &str) -> Result<Vec<String>, Error<E>> {
+    let sep = NonEmpty::<S>::SEP;
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            let mut chars = rest[1..].chars();
+            token.push(chars.next().ok_or(Error::TrailingEscape)?);
+            rest = chars.as_str();
+        } else if let Some(after) = rest.strip_prefix(sep) {
+            if !token.is_empty() {
+                tokens.push(std::mem::take(&mut token));
+            }
+            rest = after;
+        } else {
+            if !(S::SPACED && token.is_empty() && c.is_whitespace()) {
+                token.push(c);
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn write_escaped<S: Separator>(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        let leading_space = S::SPACED && rest.len() == s.len() && c.is_whitespace();
+        if c == '\\' || rest.starts_with(S::SEP) || leading_space {
+            f.write_str("\\")?;
+        }
+        write!(f, "{}", c)?;
+        rest = &rest[c.len_utf8()..];
+    }
+    Ok(())
+}
+
+impl<T: FromStr, S: Separator> FromStr for CS<T, S> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        split::<_, S>(s)?
+            .iter()
+            .map(|s| S::CASE.apply(s).parse().map_err(Error::Parse))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for CS<T, S> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for CS<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            write_escaped::<S>(f, &S::CASE.apply(&v.to_string()))?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::CommaSpace;
+    use crate::Error;
+
+    type CsTest = CS<String>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("a,,b", vec!["a", "b"]);
+        assert_ok_from_str(r"a\,b,c", vec!["a,b", "c"]);
+        assert_ok_from_str(r"a\\,b", vec![r"a\", "b"]);
+        assert_ok_from_str(r"\a\é", vec!["aé"]);
+        assert_ok_from_str(r"\,", vec![","]);
+
+        let cs: Result<CsTest, _> = r"a,b\".parse();
+        assert_eq!(cs.unwrap_err(), Error::TrailingEscape);
+        let cs: Result<CS<u32>, _> = r"1,2\,3".parse();
+        assert!(matches!(cs, Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn to_string() {
+        let cs = CsTest::from(vec!["a,b".into(), r"c\".into(), "d".into()]);
+        assert_eq!(cs.to_string(), r"a\,b,c\\,d");
+
+        let cs = CS::<_, CommaSpace>::from(vec!["a, b,c", "d"]);
+        assert_eq!(cs.to_string(), r"a\, b,c, d");
+    }
+
+    #[test]
+    fn round_trip() {
+        let values = vec!["a, b".to_string(), r"\,".into(), ",".into(), "é".into()];
+
+        let cs = CsTest::from(values.clone());
+        let parsed: Result<CsTest, _> = cs.to_string().parse();
+        assert_eq!(parsed.unwrap().0, values);
+
+        let cs = CS::<_, CommaSpace>::from(values.clone());
+        let s = serde_json::to_string(&cs).unwrap_or_default();
+        let parsed: Result<CS<String, CommaSpace>, _> = serde_json::from_str(&s);
+        assert_eq!(parsed.unwrap().0, values);
+    }
+
+    #[test]
+    fn separator_policy() {
+        use crate::separator::{Comma, Lower, Spaced, Trailing, Upper};
+
+        let values = vec![" a,b".to_string(), "c".into()];
+        let cs = CS::<_, Spaced<Comma>>::from(values.clone());
+        assert_eq!(cs.to_string(), r"\ a\,b, c");
+        let parsed: Result<CS<String, Spaced<Comma>>, _> = cs.to_string().parse();
+        assert_eq!(parsed.unwrap().0, values);
+
+        let cs = CS::<_, Trailing<Upper<Comma>>>::from(vec!["a,b", "c"]);
+        assert_eq!(cs.to_string(), r"A\,B,C,");
+        let parsed: Result<CS<String, Trailing<Lower<Comma>>>, _> = cs.to_string().parse();
+        assert_eq!(parsed.unwrap().0, ["a,b", "c"]);
+
+        let cs = CsTest::from(vec!["a".into(), "".into(), "b".into()]);
+        assert_eq!(cs.to_string(), "a,,b");
+        assert_eq!(cs.to_string().parse::<CsTest>().unwrap().0, ["a", "b"]);
+    }
+}
@@ -0,0 +1,246 @@
+//! Filter expressions such as `"age>=18,status=active,name~foo"`.
+//!
+//! Each element is split at the leftmost operator token, preferring the
+//! longest one when several match there. The operator set is chosen through
+//! the [`Operator`] trait; [`CmpOp`] covers the usual comparisons.
+//!
+//! ```rust
+//! use serde_cs::expr::{CmpOp, Condition, Conditions};
+//!
+//! let Conditions(conds) = serde_json::from_str::<Conditions<u32>>(r#""age>=18,id!=3""#).unwrap();
+//! assert_eq!(conds[0], Condition::new("age", CmpOp::Ge, 18));
+//! assert_eq!(conds[1], Condition::new("id", CmpOp::Ne, 3));
+//! ```
+
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// A set of operator tokens recognized between a field and its value.
+pub trait Operator: Copy + 'static {
+    const ALL: &'static [Self];
+
+    fn as_str(&self) -> &'static str;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Like,
+}
+
+impl Operator for CmpOp {
+    const ALL: &'static [Self] = &[
+        Self::Eq,
+        Self::Ne,
+        Self::Lt,
+        Self::Le,
+        Self::Gt,
+        Self::Ge,
+        Self::Like,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Like => "~",
+        }
+    }
+}
+
+impl fmt::Display for CmpOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Condition<V, O = CmpOp> {
+    pub field: String,
+    pub op: O,
+    pub value: V,
+}
+
+impl<V, O> Condition<V, O> {
+    #[inline]
+    pub fn new(field: impl Into<String>, op: O, value: V) -> Self {
+        Self {
+            field: field.into(),
+            op,
+            value,
+        }
+    }
+}
+
+impl<V: FromStr, O: Operator> FromStr for Condition<V, O> {
+    type Err = Error<V::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (at, op) = O::ALL
+            .iter()
+            .filter_map(|op| s.find(op.as_str()).map(|at| (at, *op)))
+            .min_by_key(|(at, op)| (*at, usize::MAX - op.as_str().len()))
+            .ok_or(Error::MissingOperator)?;
+
+        let field = &s[..at];
+        if field.is_empty() {
+            return Err(Error::Empty);
+        }
+        let value = s[at + op.as_str().len()..].parse()?;
+        Ok(Self::new(field, op, value))
+    }
+}
+
+impl<V: fmt::Display, O: Operator> fmt::Display for Condition<V, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.field, self.op.as_str(), self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conditions<V = String, O = CmpOp>(pub Vec<Condition<V, O>>);
+
+list_wrapper! {
+    impl[V, O] Conditions[V, O](Vec<Condition<V, O>>):
+        Default, From, Inner, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of filter expressions"))
+}
+
+impl<V: FromStr, O: Operator> FromStr for Conditions<V, O> {
+    type Err = Error<V::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(Condition::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<V, O> IntoIterator for Conditions<V, O> {
+    type Item = Condition<V, O>;
+    type IntoIter = vec::IntoIter<Condition<V, O>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<V: fmt::Display, O: Operator> fmt::Display for Conditions<V, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <Condition<V, O> as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CmpOp, Condition, Conditions, Operator};
+    use crate::Error;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<Condition<String>>) {
+        let conds: Result<Conditions, _> = s.parse();
+        assert!(matches!(conds, Ok(v) if v == Conditions(expected)))
+    }
+
+    fn cond(field: &str, op: CmpOp, value: &str) -> Condition<String> {
+        Condition::new(field, op, value.to_string())
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str(
+            "age>=18,status=active,name~foo",
+            vec![
+                cond("age", CmpOp::Ge, "18"),
+                cond("status", CmpOp::Eq, "active"),
+                cond("name", CmpOp::Like, "foo"),
+            ],
+        );
+        assert_ok_from_str(
+            "a!=b,a<b,a<=b,a>b",
+            vec![
+                cond("a", CmpOp::Ne, "b"),
+                cond("a", CmpOp::Lt, "b"),
+                cond("a", CmpOp::Le, "b"),
+                cond("a", CmpOp::Gt, "b"),
+            ],
+        );
+        assert_ok_from_str("a=", vec![cond("a", CmpOp::Eq, "")]);
+        assert_ok_from_str("a=b=c", vec![cond("a", CmpOp::Eq, "b=c")]);
+
+        assert_eq!(
+            "a".parse::<Conditions>().unwrap_err(),
+            Error::MissingOperator
+        );
+        assert_eq!("=a".parse::<Conditions>().unwrap_err(), Error::Empty);
+        assert!("age>=x".parse::<Conditions<u32>>().is_err());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Colon {
+        Is,
+        Not,
+    }
+
+    impl Operator for Colon {
+        const ALL: &'static [Self] = &[Self::Is, Self::Not];
+
+        fn as_str(&self) -> &'static str {
+            match self {
+                Self::Is => ":",
+                Self::Not => ":!",
+            }
+        }
+    }
+
+    #[test]
+    fn custom_operator() {
+        let conds: Result<Conditions<u32, Colon>, _> = "a:1,b:!2".parse();
+        let expected = vec![
+            Condition::new("a", Colon::Is, 1),
+            Condition::new("b", Colon::Not, 2),
+        ];
+        assert_eq!(conds.unwrap(), Conditions(expected));
+
+        assert!("a=1".parse::<Conditions<u32, Colon>>().is_err());
+    }
+
+    #[test]
+    fn serde() {
+        let conds: Result<Conditions<u32>, _> = serde_json::from_str(r#""a>1,b<=2""#);
+        let expected = vec![
+            Condition::new("a", CmpOp::Gt, 1),
+            Condition::new("b", CmpOp::Le, 2),
+        ];
+        assert_eq!(conds.unwrap(), Conditions(expected));
+
+        let s = serde_json::to_string(&Conditions(vec![
+            Condition::new("a", CmpOp::Ne, 1),
+            Condition::new("b", CmpOp::Like, 2),
+        ]));
+        assert_eq!(s.unwrap(), r#""a!=1,b~2""#);
+    }
+}
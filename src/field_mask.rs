@@ -0,0 +1,118 @@
+//! Field masks in the JSON encoding of `google.protobuf.FieldMask`.
+//!
+//! ```rust
+//! use serde_cs::field_mask::FieldMask;
+//!
+//! let mask: FieldMask = serde_json::from_str(r#""user.name,user.email,photo""#).unwrap();
+//! assert!(mask.covers("user.name"));
+//! assert!(mask.covers("photo.url"));
+//! assert!(!mask.covers("user"));
+//! assert!(!mask.covers("user.nameX"));
+//! ```
+
+use crate::Error;
+
+use std::convert::Infallible;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldMask(pub Vec<String>);
+
+list_wrapper! {
+    impl[] FieldMask[](Vec<String>):
+        From, Inner, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of field paths"))
+}
+
+impl FieldMask {
+    #[inline]
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// Whether `path` is one of the paths in the mask or lies beneath one of them.
+    pub fn covers(&self, path: &str) -> bool {
+        self.paths().any(|p| match path.strip_prefix(p) {
+            Some(rest) => rest.is_empty() || rest.starts_with('.'),
+            None => false,
+        })
+    }
+}
+
+impl FromStr for FieldMask {
+    type Err = Error<Infallible>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(|path| {
+                if path.split('.').any(str::is_empty) {
+                    Err(Error::Empty)
+                } else {
+                    Ok(path.to_string())
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl IntoIterator for FieldMask {
+    type Item = String;
+    type IntoIter = vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl fmt::Display for FieldMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldMask;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let mask: Result<FieldMask, _> = s.parse();
+        assert!(matches!(mask, Ok(FieldMask(v)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str("a", vec!["a"]);
+        assert_ok_from_str(",a.b,,c,", vec!["a.b", "c"]);
+
+        assert!("a.".parse::<FieldMask>().is_err());
+        assert!(".a".parse::<FieldMask>().is_err());
+        assert!("a..b".parse::<FieldMask>().is_err());
+    }
+
+    #[test]
+    fn covers() {
+        let mask: FieldMask = "user.name,photo".parse().unwrap();
+        assert!(mask.covers("user.name"));
+        assert!(mask.covers("user.name.first"));
+        assert!(mask.covers("photo"));
+        assert!(mask.covers("photo.url"));
+
+        assert!(!mask.covers("user"));
+        assert!(!mask.covers("user.email"));
+        assert!(!mask.covers("photos"));
+        assert!(!FieldMask::default().covers("a"));
+    }
+
+    #[test]
+    fn serde() {
+        let mask: Result<FieldMask, _> = serde_json::from_str(r#""a.b,c""#);
+        assert_eq!(mask.unwrap().0, ["a.b", "c"]);
+
+        let s = serde_json::to_string(&FieldMask(vec!["a.b".into(), "c".into()]));
+        assert_eq!(s.unwrap(), r#""a.b,c""#);
+    }
+}
@@ -0,0 +1,144 @@
+//! Include/exclude lists, where a `!` prefix marks an excluded element.
+//!
+//! ```rust
+//! use serde_cs::filter::Filter;
+//!
+//! let filter: Filter<String> = serde_json::from_str(r#""a,b,!c""#).unwrap();
+//! assert_eq!(filter.include, vec!["a", "b"]);
+//! assert_eq!(filter.exclude, vec!["c"]);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter<T> {
+    pub include: Vec<T>,
+    pub exclude: Vec<T>,
+}
+
+list_wrapper! {
+    impl[T] Filter[T]:
+        Serialize,
+        Deserialize(|f| f.write_str("comma separated list of optionally `!` prefixed values"))
+}
+
+impl<T> Default for Filter<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl<T> Filter<T> {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether `x` passes: it must not be excluded, and must be included
+    /// unless the include list is empty.
+    pub fn matches(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        (self.include.is_empty() || self.include.contains(x)) && !self.exclude.contains(x)
+    }
+}
+
+impl<T: FromStr> FromStr for Filter<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Self::default();
+
+        for s in s.split(',').filter(|s| !s.is_empty()) {
+            match s.strip_prefix('!') {
+                Some(s) => filter.exclude.push(s.parse()?),
+                None => filter.include.push(s.parse()?),
+            }
+        }
+        Ok(filter)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Filter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let include = self.include.iter().map(|v| (v, ""));
+        let exclude = self.exclude.iter().map(|v| (v, "!"));
+        let mut it = include.chain(exclude);
+
+        if let Some((v, prefix)) = it.next() {
+            write!(f, "{}{}", prefix, v)?;
+        }
+
+        for (v, prefix) in it {
+            write!(f, ",{}{}", prefix, v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filter;
+    type FilterTest = Filter<u32>;
+
+    fn assert_ok_from_str(s: &str, include: Vec<u32>, exclude: Vec<u32>) {
+        let filter: Result<FilterTest, _> = s.parse();
+        assert!(matches!(filter, Ok(v) if v == Filter { include, exclude }))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![], vec![]);
+        assert_ok_from_str(",,", vec![], vec![]);
+        assert_ok_from_str("1,2", vec![1, 2], vec![]);
+        assert_ok_from_str("!1,,!2", vec![], vec![1, 2]);
+        assert_ok_from_str("1,!2,3", vec![1, 3], vec![2]);
+
+        assert!("!".parse::<FilterTest>().is_err());
+        assert!("!!1".parse::<FilterTest>().is_err());
+        assert!("1,!a".parse::<FilterTest>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(FilterTest::default().to_string(), "");
+        let filter = Filter {
+            include: vec![1, 3],
+            exclude: vec![2],
+        };
+        assert_eq!(filter.to_string(), "1,3,!2");
+    }
+
+    #[test]
+    fn matches() {
+        let filter: FilterTest = "!2".parse().unwrap();
+        assert!(filter.matches(&1));
+        assert!(!filter.matches(&2));
+
+        let filter: FilterTest = "1,2,!2".parse().unwrap();
+        assert!(filter.matches(&1));
+        assert!(!filter.matches(&2));
+        assert!(!filter.matches(&3));
+    }
+
+    #[test]
+    fn serde() {
+        let filter: Result<FilterTest, _> = serde_json::from_str(r#""1,!2""#);
+        let v = filter.unwrap();
+        assert_eq!(v.include, [1]);
+        assert_eq!(v.exclude, [2]);
+
+        let s = serde_json::to_string(&Filter {
+            include: vec![1],
+            exclude: vec![2, 3],
+        });
+        assert_eq!(s.unwrap(), r#""1,!2,!3""#);
+    }
+}
@@ -0,0 +1,129 @@
+//! Serialization into fixed-size buffers, without allocating.
+//!
+//! ```rust
+//! use serde_cs::vec::CS;
+//!
+//! let mut buf = [0u8; 8];
+//! let len = CS::new(vec![1, 22, 333]).write_to_slice(&mut buf).unwrap();
+//! assert_eq!(&buf[..len], b"1,22,333");
+//!
+//! assert!(CS::new(vec![1, 22, 333, 4]).write_to_slice(&mut buf).is_err());
+//! ```
+
+use crate::separator::Separator;
+
+use std::error;
+use std::fmt::{self, Write};
+
+/// The joined form did not fit into the destination buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer too small")
+    }
+}
+
+impl error::Error for BufferTooSmall {}
+
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn write_display(buf: &mut [u8], v: &impl fmt::Display) -> Result<usize, BufferTooSmall> {
+    let mut w = SliceWriter { buf, len: 0 };
+    write!(w, "{}", v).map_err(|_| BufferTooSmall)?;
+    Ok(w.len)
+}
+
+#[cfg(feature = "heapless")]
+fn to_heapless<const N: usize>(
+    v: &impl fmt::Display,
+) -> Result<heapless::String<N>, BufferTooSmall> {
+    let mut s = heapless::String::new();
+    write!(s, "{}", v).map_err(|_| BufferTooSmall)?;
+    Ok(s)
+}
+
+impl<T: fmt::Display, S: Separator> crate::vec::CS<T, S> {
+    /// Writes the joined form to the start of `buf` and returns its length.
+    /// The contents of `buf` are unspecified on error.
+    #[inline]
+    pub fn write_to_slice(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        write_display(buf, self)
+    }
+
+    #[cfg(feature = "heapless")]
+    #[inline]
+    pub fn to_heapless<const N: usize>(&self) -> Result<heapless::String<N>, BufferTooSmall> {
+        to_heapless(self)
+    }
+}
+
+impl<T: fmt::Display, const LEN: usize, S: Separator, P> crate::array::CS<T, LEN, S, P> {
+    /// Writes the joined form to the start of `buf` and returns its length.
+    /// The contents of `buf` are unspecified on error.
+    #[inline]
+    pub fn write_to_slice(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        write_display(buf, self)
+    }
+
+    #[cfg(feature = "heapless")]
+    #[inline]
+    pub fn to_heapless<const N: usize>(&self) -> Result<heapless::String<N>, BufferTooSmall> {
+        to_heapless(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BufferTooSmall;
+    use crate::{array, vec};
+
+    #[test]
+    fn write_to_slice() {
+        let mut buf = [0u8; 5];
+        assert_eq!(vec::CS::<u32>::new(vec![]).write_to_slice(&mut buf), Ok(0));
+        assert_eq!(vec::CS::new(vec![1, 2, 3]).write_to_slice(&mut buf), Ok(5));
+        assert_eq!(&buf, b"1,2,3");
+        assert_eq!(array::CS::new([10, 2]).write_to_slice(&mut buf), Ok(4));
+        assert_eq!(&buf[..4], b"10,2");
+
+        assert_eq!(
+            vec::CS::new(vec![1, 2, 34]).write_to_slice(&mut buf),
+            Err(BufferTooSmall)
+        );
+        assert_eq!(
+            vec::CS::new(vec![1]).write_to_slice(&mut []),
+            Err(BufferTooSmall)
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn to_heapless() {
+        let s = vec::CS::new(vec![1, 2]).to_heapless::<3>();
+        assert_eq!(s.unwrap(), "1,2");
+        let s = array::CS::new(["ab", "c"]).to_heapless::<4>();
+        assert_eq!(s.unwrap(), "ab,c");
+
+        assert_eq!(
+            vec::CS::new(vec![1, 2]).to_heapless::<2>(),
+            Err(BufferTooSmall)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Lists whose elements are written through an [`ItemFormat`] instead of
+//! their own `Display`, e.g. to fix the precision of floats.
+//!
+//! Parsing still goes through `FromStr`. For one-off formatting of an
+//! existing list, [`CS::map_display`](crate::vec::CS::map_display) takes a
+//! closure instead.
+//!
+//! ```rust
+//! use serde_cs::formatted::{Precision, CS};
+//!
+//! let cs: CS<f64, Precision<2>> = serde_json::from_str(r#""1.5,2.125""#).unwrap();
+//! assert_eq!(serde_json::to_string(&cs).unwrap(), r#""1.50,2.12""#);
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::vec;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Writes one element of a list.
+pub trait ItemFormat<T> {
+    fn fmt(v: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// `Display` with `N` digits after the decimal point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Precision<const N: usize>;
+
+impl<T: fmt::Display, const N: usize> ItemFormat<T> for Precision<N> {
+    #[inline]
+    fn fmt(v: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", N, v)
+    }
+}
+
+pub struct CS<T, F, S = Comma>(pub Vec<T>, pub PhantomData<(F, S)>);
+
+list_wrapper! {
+    impl[T, F, S] CS[T, F, S](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T: FromStr, F, S: Separator> FromStr for CS<T, F, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<vec::CS<T, S>>()
+            .map(|cs| Self::from(cs.into_inner()))
+    }
+}
+
+impl<T, F, S> IntoIterator for CS<T, F, S> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T, F: ItemFormat<T>, S: Separator> fmt::Display for CS<T, F, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            F::fmt(v, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ItemFormat, Precision, CS};
+    use crate::separator::Semicolon;
+
+    use std::fmt;
+
+    struct Hex;
+
+    impl ItemFormat<u32> for Hex {
+        fn fmt(v: &u32, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "0x{:x}", v)
+        }
+    }
+
+    struct Prefixed;
+
+    impl<T: fmt::Display> ItemFormat<T> for Prefixed {
+        fn fmt(v: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "tag:{}", v)
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(CS::<u32, Hex>::default().to_string(), "");
+        assert_eq!(CS::<_, Hex>::from(vec![255, 16]).to_string(), "0xff,0x10");
+        assert_eq!(
+            CS::<_, Prefixed, Semicolon>::from(vec!["a", "b"]).to_string(),
+            "tag:a;tag:b"
+        );
+        assert_eq!(
+            CS::<_, Precision<1>>::from(vec![1.0, 0.25]).to_string(),
+            "1.0,0.2"
+        );
+        assert_eq!(
+            format!("{:#}", CS::<_, Precision<0>>::from(vec![1.6, 2.4])),
+            "2, 2"
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<f32, Precision<3>>, _> = serde_json::from_str(r#""1,,2.5""#);
+        assert_eq!(cs.as_ref().unwrap().0, [1.0, 2.5]);
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""1.000,2.500""#);
+
+        let cs: Result<CS<u32, Hex>, _> = serde_json::from_str(r#""0x1""#);
+        assert!(cs.is_err());
+    }
+}
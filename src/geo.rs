@@ -0,0 +1,166 @@
+//! WKT-style coordinate lists: comma separated points whose coordinates are
+//! separated by whitespace.
+//!
+//! ```rust
+//! use serde_cs::geo::Coordinates;
+//!
+//! let line: Coordinates = serde_json::from_str(r#""30 10, 40 40, 20 40""#).unwrap();
+//! assert_eq!(line.0, vec![(30.0, 10.0), (40.0, 40.0), (20.0, 40.0)]);
+//!
+//! let s = serde_json::to_string(&line).unwrap();
+//! assert_eq!(s, r#""30 10, 40 40, 20 40""#);
+//! ```
+
+use crate::Error;
+
+use std::num::ParseFloatError;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Coordinates(pub Vec<(f64, f64)>);
+
+list_wrapper! {
+    impl[] Coordinates[](Vec<(f64, f64)>): AsRef[(f64, f64)], From, Serialize, Deserialize(|f| {
+        f.write_str("comma separated list of space separated coordinate pairs")
+    })
+}
+
+impl Coordinates {
+    #[inline]
+    pub fn into_inner(self) -> Vec<(f64, f64)> {
+        self.0
+    }
+}
+
+fn parse_point(s: &str) -> Result<(f64, f64), Error<ParseFloatError>> {
+    let mut it = s.split_whitespace();
+    match (it.next(), it.next(), it.next()) {
+        (Some(x), Some(y), None) => Ok((x.parse()?, y.parse()?)),
+        _ => Err(Error::InvalidPoint),
+    }
+}
+
+impl FromStr for Coordinates {
+    type Err = Error<ParseFloatError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(parse_point)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl IntoIterator for Coordinates {
+    type Item = (f64, f64);
+    type IntoIter = vec::IntoIter<(f64, f64)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl fmt::Display for Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some((x, y)) = it.next() {
+            write!(f, "{} {}", x, y)?;
+        }
+
+        for (x, y) in it {
+            write!(f, ", {} {}", x, y)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+mod geo_types_impls {
+    use super::Coordinates;
+
+    use geo_types::{Coord, LineString};
+
+    impl From<Coordinates> for Vec<Coord> {
+        fn from(cs: Coordinates) -> Self {
+            cs.0.into_iter().map(Coord::from).collect()
+        }
+    }
+
+    impl From<Coordinates> for LineString {
+        #[inline]
+        fn from(cs: Coordinates) -> Self {
+            Self::new(cs.into())
+        }
+    }
+
+    impl From<LineString> for Coordinates {
+        fn from(line: LineString) -> Self {
+            Self(line.into_iter().map(|c| c.x_y()).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Coordinates;
+    use crate::Error;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<(f64, f64)>) {
+        let cs: Result<Coordinates, _> = s.parse();
+        assert!(matches!(cs, Ok(Coordinates(v)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(" , ,", vec![]);
+        assert_ok_from_str("1 2", vec![(1.0, 2.0)]);
+        assert_ok_from_str(
+            "30 10,40  40 ,\t20 -4.5",
+            vec![(30.0, 10.0), (40.0, 40.0), (20.0, -4.5)],
+        );
+
+        assert_eq!("1".parse::<Coordinates>().unwrap_err(), Error::InvalidPoint);
+        assert_eq!(
+            "1 2 3".parse::<Coordinates>().unwrap_err(),
+            Error::InvalidPoint
+        );
+        assert!(matches!("1 a".parse::<Coordinates>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Coordinates::default().to_string(), "");
+        assert_eq!(Coordinates(vec![(1.5, -2.0)]).to_string(), "1.5 -2");
+        assert_eq!(
+            Coordinates(vec![(1.0, 2.0), (3.0, 4.0)]).to_string(),
+            "1 2, 3 4"
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<Coordinates, _> = serde_json::from_str(r#""1 2, 3 4""#);
+        assert_eq!(cs.unwrap().0, [(1.0, 2.0), (3.0, 4.0)]);
+
+        let cs: Result<Coordinates, _> = serde_json::from_str("[1, 2]");
+        assert!(cs.is_err());
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types() {
+        use geo_types::{coord, LineString};
+
+        let line = LineString::from("1 2, 3 4".parse::<Coordinates>().unwrap());
+        assert_eq!(
+            line.0,
+            vec![coord! { x: 1.0, y: 2.0 }, coord! { x: 3.0, y: 4.0 }]
+        );
+        assert_eq!(Coordinates::from(line).to_string(), "1 2, 3 4");
+    }
+}
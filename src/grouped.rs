@@ -0,0 +1,209 @@
+//! Keyed groups of lists in one string, e.g. `"a:1,2;b:3,4"`.
+//!
+//! Groups with the same key are merged. Formatting orders the groups by the
+//! formatted key, so the output does not depend on the hash map's order.
+//!
+//! ```rust
+//! use serde_cs::grouped::Grouped;
+//!
+//! let g: Grouped<String, u32> = serde_json::from_str(r#""b:3,4;a:1,2""#).unwrap();
+//! assert_eq!(g.0["a"], vec![1, 2]);
+//!
+//! let s = serde_json::to_string(&g).unwrap();
+//! assert_eq!(s, r#""a:1,2;b:3,4""#);
+//! ```
+
+use crate::Error;
+
+use std::collections::{hash_map, HashMap};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{error, fmt};
+
+/// The separators between groups, between a key and its items, and between items.
+pub trait GroupSyntax {
+    const GROUP: &'static str;
+    const KEY: &'static str;
+    const ITEM: &'static str;
+}
+
+/// `key:item,item;key:item`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Standard;
+
+impl GroupSyntax for Standard {
+    const GROUP: &'static str = ";";
+    const KEY: &'static str = ":";
+    const ITEM: &'static str = ",";
+}
+
+/// Whether the key or one of the items of a group failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupError<K, V> {
+    Key(K),
+    Item(V),
+}
+
+impl<K: fmt::Display, V: fmt::Display> fmt::Display for GroupError<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Key(e) => e.fmt(f),
+            Self::Item(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<K, V> error::Error for GroupError<K, V>
+where
+    K: error::Error + 'static,
+    V: error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Key(e) => Some(e),
+            Self::Item(e) => Some(e),
+        }
+    }
+}
+
+pub struct Grouped<K, V, S = Standard>(pub HashMap<K, Vec<V>>, PhantomData<S>);
+
+list_wrapper! {
+    impl[K, V, S] Grouped[K, V, S](HashMap<K, Vec<V>>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Serialize,
+        Deserialize(|f| f.write_str("separated list of keyed groups"))
+}
+
+impl<K, V, S> Grouped<K, V, S> {
+    #[inline]
+    pub fn into_inner(self) -> HashMap<K, Vec<V>> {
+        self.0
+    }
+}
+
+impl<K, V, S> FromStr for Grouped<K, V, S>
+where
+    K: FromStr + Eq + Hash,
+    V: FromStr,
+    S: GroupSyntax,
+{
+    type Err = Error<GroupError<K::Err, V::Err>>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map: HashMap<K, Vec<V>> = HashMap::new();
+
+        for group in s.split(S::GROUP).filter(|s| !s.is_empty()) {
+            let (key, values) = group.split_once(S::KEY).ok_or(Error::MissingDelimiter)?;
+            let key = key.parse().map_err(GroupError::Key)?;
+            let items = map.entry(key).or_default();
+            for v in values.split(S::ITEM).filter(|s| !s.is_empty()) {
+                items.push(v.parse().map_err(GroupError::Item)?);
+            }
+        }
+        Ok(Self::from(map))
+    }
+}
+
+impl<K, V, S> IntoIterator for Grouped<K, V, S> {
+    type Item = (K, Vec<V>);
+    type IntoIter = hash_map::IntoIter<K, Vec<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V, S> fmt::Display for Grouped<K, V, S>
+where
+    K: fmt::Display,
+    V: fmt::Display,
+    S: GroupSyntax,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut groups: Vec<_> = self.0.iter().map(|(k, v)| (k.to_string(), v)).collect();
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (i, (key, items)) in groups.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(S::GROUP)?;
+            }
+            write!(f, "{}{}", key, S::KEY)?;
+            for (j, v) in items.iter().enumerate() {
+                if j > 0 {
+                    f.write_str(S::ITEM)?;
+                }
+                write!(f, "{}", v)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GroupError, GroupSyntax, Grouped};
+    use crate::Error;
+
+    use std::collections::HashMap;
+
+    type GroupedTest = Grouped<String, u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<(&str, Vec<u32>)>) {
+        let expected: HashMap<_, _> = expected
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let g: Result<GroupedTest, _> = s.parse();
+        assert!(matches!(g, Ok(Grouped(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(";;", vec![]);
+        assert_ok_from_str("a:", vec![("a", vec![])]);
+        assert_ok_from_str("a:1,,2;b:3", vec![("a", vec![1, 2]), ("b", vec![3])]);
+        assert_ok_from_str("a:1;b:2;a:3", vec![("a", vec![1, 3]), ("b", vec![2])]);
+
+        let g: Result<GroupedTest, _> = "a:1;b".parse();
+        assert_eq!(g.unwrap_err(), Error::MissingDelimiter);
+        let g: Result<GroupedTest, _> = "a:1,x".parse();
+        assert!(matches!(g, Err(Error::Parse(GroupError::Item(_)))));
+        let g: Result<Grouped<u32, u32>, _> = "a:1".parse();
+        assert!(matches!(g, Err(Error::Parse(GroupError::Key(_)))));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(GroupedTest::default().to_string(), "");
+
+        let g: GroupedTest = "c:;b:2;a:1,3".parse().unwrap();
+        assert_eq!(g.to_string(), "a:1,3;b:2;c:");
+    }
+
+    struct Pipes;
+
+    impl GroupSyntax for Pipes {
+        const GROUP: &'static str = "|";
+        const KEY: &'static str = "=";
+        const ITEM: &'static str = " ";
+    }
+
+    #[test]
+    fn custom_syntax() {
+        let g: Grouped<String, u32, Pipes> = "x=1 2|y=3".parse().unwrap();
+        assert_eq!(g.0["x"], vec![1, 2]);
+        assert_eq!(g.to_string(), "x=1 2|y=3");
+    }
+
+    #[test]
+    fn serde() {
+        let g: Result<GroupedTest, _> = serde_json::from_str(r#""a:1""#);
+        assert_eq!(g.unwrap().0["a"], [1]);
+
+        let g: GroupedTest = "b:1;a:2".parse().unwrap();
+        assert_eq!(serde_json::to_string(&g).unwrap(), r#""a:2;b:1""#);
+    }
+}
@@ -0,0 +1,121 @@
+//! Lists parsed into a [`BinaryHeap`], so the largest element can be popped
+//! first.
+//!
+//! ```rust
+//! use serde_cs::heap::CS;
+//!
+//! let mut cs: CS<u32> = serde_json::from_str(r#""2,5,1""#).unwrap();
+//! assert_eq!(cs.0.pop(), Some(5));
+//! assert_eq!(cs.to_string(), "2,1");
+//! ```
+//!
+//! The iteration order of a heap is unspecified, so elements are formatted
+//! in the order they would be popped, largest first.
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::vec;
+
+use std::cmp::Reverse;
+use std::collections::{binary_heap, BinaryHeap};
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct CS<T, S = Comma>(pub BinaryHeap<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](BinaryHeap<T>, PhantomData):
+        Debug, Clone, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T: Ord, S> From<vec::CS<T, S>> for CS<T, S> {
+    #[inline]
+    fn from(cs: vec::CS<T, S>) -> Self {
+        Self::from(BinaryHeap::from(cs.into_inner()))
+    }
+}
+
+impl<T: FromStr + Ord, S: Separator> FromStr for CS<T, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<vec::CS<T, S>>().map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for CS<T, S> {
+    type Item = T;
+    type IntoIter = binary_heap::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display + Ord, S: Separator> fmt::Display for CS<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sorted: Vec<_> = self.0.iter().map(Reverse).collect();
+        sorted.sort_unstable();
+
+        for (i, Reverse(v)) in sorted.into_iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::Pipe;
+    use crate::vec;
+
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    type CsTest = CS<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let cs: Result<CsTest, _> = s.parse();
+        let sorted = cs.map(|cs| cs.into_inner().into_sorted_vec());
+        assert!(matches!(sorted, Ok(v) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",3,,1,2,", vec![1, 2, 3]);
+        assert!("1,a".parse::<CsTest>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let mut cs = CsTest::from(BinaryHeap::from([1, 3, 2]));
+        assert_eq!(cs.to_string(), "3,2,1");
+        cs.0.pop();
+        assert_eq!(format!("{:#}", cs), "2, 1");
+
+        let cs = CS::<_, Pipe>::from(BinaryHeap::from([Reverse(2), Reverse(1)]));
+        assert_eq!(cs.0.peek(), Some(&Reverse(1)));
+        assert_eq!(CS::<u32>::from(vec::CS::new(vec![1, 2])).to_string(), "2,1");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String, Pipe>, _> = serde_json::from_str(r#""a|c|b""#);
+        let top = cs.map(|mut cs| cs.0.pop());
+        assert_eq!(top.unwrap().as_deref(), Some("c"));
+
+        let s = serde_json::to_string(&CsTest::from(BinaryHeap::from([1, 2])));
+        assert_eq!(s.unwrap(), r#""2,1""#);
+    }
+}
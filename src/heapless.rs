@@ -0,0 +1,159 @@
+//! Lists of at most `N` elements in a [`heapless::Vec`], parsed and written
+//! without an allocator.
+//!
+//! Requires the `heapless` feature, and works without the default `std`
+//! feature. Input with more than `N` elements fails with [`Error::TooMany`].
+//! Without `std`, separators that change case such as
+//! [`Lower`](crate::separator::Lower) are rejected at compile time.
+//!
+//! ```rust
+//! use serde_cs::heapless::CS;
+//! use serde_cs::Error;
+//!
+//! let cs: CS<u16, 4> = serde_json::from_str(r#""100,250""#).unwrap();
+//! assert_eq!(cs.0.as_slice(), [100, 250]);
+//! assert_eq!(cs.to_string(), "100,250");
+//!
+//! let cs: Result<CS<u16, 1>, _> = "1,2".parse();
+//! assert!(matches!(cs, Err(Error::TooMany { max: 1 })));
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use heapless::Vec;
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+pub struct CS<T, const N: usize, S = Comma>(pub Vec<T, N>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, const N: usize, S] CS[T, N, S](Vec<T, N>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| {
+            write!(f, "list of at most {} elements separated by {:?}", N, S::SEP)
+        })
+}
+
+#[cfg(feature = "std")]
+fn parse<T: FromStr, S: Separator>(s: &str) -> Result<T, T::Err> {
+    S::CASE.apply(s).parse()
+}
+
+#[cfg(not(feature = "std"))]
+fn parse<T: FromStr, S: Separator>(s: &str) -> Result<T, T::Err> {
+    struct Check<S>(PhantomData<S>);
+
+    impl<S: Separator> Check<S> {
+        const PRESERVE: () = assert!(
+            matches!(S::CASE, crate::separator::Case::Preserve),
+            "changing the case of segments needs the `std` feature"
+        );
+    }
+
+    let () = Check::<S>::PRESERVE;
+    s.parse()
+}
+
+impl<T: FromStr, const N: usize, S: Separator> FromStr for CS<T, N, S> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut v = Vec::new();
+        for s in S::split(s).filter(|s| !s.is_empty()) {
+            let item = parse::<T, S>(s)?;
+            v.push(item).map_err(|_| Error::TooMany { max: N })?;
+        }
+        Ok(Self::from(v))
+    }
+}
+
+impl<T, const N: usize, S> IntoIterator for CS<T, N, S> {
+    type Item = T;
+    type IntoIter = <Vec<T, N> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator> fmt::Display for CS<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::{Pipe, Trailing};
+    use crate::Error;
+
+    use core::fmt::Write;
+
+    use heapless::{String, Vec};
+
+    type CsTest = CS<u32, 3>;
+
+    fn assert_ok_from_str(s: &str, expected: &[u32]) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v.as_slice() == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", &[]);
+        assert_ok_from_str(",1,,2,", &[1, 2]);
+        assert_ok_from_str("1,2,3,,", &[1, 2, 3]);
+
+        assert_eq!(
+            "1,2,3,4".parse::<CsTest>().unwrap_err(),
+            Error::TooMany { max: 3 }
+        );
+        assert!(matches!("1,a".parse::<CsTest>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn to_string() {
+        let mut s = String::<16>::new();
+        write!(s, "{}", CsTest::default()).unwrap();
+        assert_eq!(s, "");
+
+        let cs = CsTest::from(Vec::from_slice(&[1, 2, 3]).unwrap());
+        s.clear();
+        write!(s, "{:#}", cs).unwrap();
+        assert_eq!(s, "1, 2, 3");
+
+        let cs = CS::<_, 2, Trailing<Pipe>>::from(Vec::from_slice(&[1, 2]).unwrap());
+        s.clear();
+        write!(s, "{}", cs).unwrap();
+        assert_eq!(s, "1|2|");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<char, 2, Pipe>, _> = serde_json::from_str(r#""a|b""#);
+        assert_eq!(cs.unwrap().0.as_slice(), ['a', 'b']);
+        let cs: Result<CS<char, 2, Pipe>, _> = serde_json::from_str(r#""a|b|c""#);
+        assert!(cs.is_err());
+
+        let s = serde_json::to_string(&CsTest::from(Vec::from_slice(&[1, 2, 3]).unwrap()));
+        assert_eq!(s.unwrap(), r#""1,2,3""#);
+    }
+}
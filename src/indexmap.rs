@@ -0,0 +1,191 @@
+//! Sets and key–value lists that keep their first-seen order, backed by
+//! [`IndexSet`] and [`IndexMap`].
+//!
+//! Requires the `indexmap` feature.
+//!
+//! ```rust
+//! use serde_cs::indexmap::{Map, Set};
+//!
+//! let labels: Set<String> = serde_json::from_str(r#""urgent,backend,urgent,ui""#).unwrap();
+//! assert_eq!(labels.to_string(), "urgent,backend,ui");
+//!
+//! let map: Map<String, u32> = "b=2,a=1,b=3".parse().unwrap();
+//! assert_eq!(map.0["b"], 3);
+//! assert_eq!(map.to_string(), "b=3,a=1");
+//! ```
+//!
+//! A repeated key keeps the position of its first occurrence and the value of
+//! its last.
+
+use crate::map::{parse_entry, EntryError, Equals};
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use indexmap::{map, set, IndexMap, IndexSet};
+
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A set that formats its elements in insertion order.
+pub struct Set<T, S = Comma>(pub IndexSet<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] Set[T, S](IndexSet<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T: FromStr + Eq + Hash, S: Separator> FromStr for Set<T, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        S::split(s)
+            .filter(|s| !s.is_empty())
+            .map(|s| S::CASE.apply(s).parse())
+            .collect::<Result<IndexSet<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for Set<T, S> {
+    type Item = T;
+    type IntoIter = set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for Set<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A key–value list that formats its entries in insertion order.
+pub struct Map<K, V, S = Comma, D = Equals>(pub IndexMap<K, V>, pub PhantomData<(S, D)>);
+
+list_wrapper! {
+    impl[K, V, S, D] Map[K, V, S, D](IndexMap<K, V>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([D: Separator, S: Separator] |f| {
+            write!(f, "key{}value pairs separated by {:?}", D::SEP, S::SEP)
+        })
+}
+
+impl<K, V, S, D> FromStr for Map<K, V, S, D>
+where
+    K: FromStr + Eq + Hash,
+    V: FromStr,
+    S: Separator,
+    D: Separator,
+{
+    type Err = Error<EntryError<K::Err, V::Err>>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        S::split(s)
+            .filter(|s| !s.is_empty())
+            .map(|s| parse_entry(&S::CASE.apply(s), D::SEP))
+            .collect::<Result<IndexMap<_, _>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<K, V, S, D> IntoIterator for Map<K, V, S, D> {
+    type Item = (K, V);
+    type IntoIter = map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V, S, D> fmt::Display for Map<K, V, S, D>
+where
+    K: fmt::Display,
+    V: fmt::Display,
+    S: Separator,
+    D: Separator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (k, v)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(&format_args!("{}{}{}", k, D::SEP, v), f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, Set};
+    use crate::map::{Colon, EntryError};
+    use crate::separator::{Comma, Lower, Semicolon, Space, Trailing, Upper};
+    use crate::Error;
+
+    #[test]
+    fn set() {
+        let set: Result<Set<u32>, _> = ",3,1,,3,2".parse();
+        assert!(set.as_ref().unwrap().0.iter().eq(&[3, 1, 2]));
+        assert_eq!(set.map(|set| set.to_string()), Ok("3,1,2".to_string()));
+
+        let set: Result<Set<String, Space>, _> = serde_json::from_str(r#""b a b""#);
+        let s = serde_json::to_string(&set.unwrap());
+        assert_eq!(s.unwrap(), r#""b a""#);
+
+        assert!("1,x".parse::<Set<u32>>().is_err());
+    }
+
+    #[test]
+    fn map() {
+        let map: Result<Map<String, u32>, _> = "z=1,a=2,z=3".parse();
+        assert_eq!(
+            map.as_ref().unwrap().0.get_index(0),
+            Some((&"z".to_string(), &3))
+        );
+        assert_eq!(map.map(|map| map.to_string()), Ok("z=3,a=2".to_string()));
+
+        let map: Result<Map<String, u32, Semicolon, Colon>, _> =
+            serde_json::from_str(r#""b:1;a:2""#);
+        let s = serde_json::to_string(&map.unwrap());
+        assert_eq!(s.unwrap(), r#""b:1;a:2""#);
+
+        let map: Result<Map<String, u32>, _> = "a".parse();
+        assert_eq!(map.unwrap_err(), Error::MissingDelimiter);
+        let map: Result<Map<String, u32>, _> = "a=x".parse();
+        assert!(matches!(map, Err(Error::Parse(EntryError::Value(_)))));
+    }
+
+    #[test]
+    fn separator_policy() {
+        let set: Result<Set<String, Lower<Comma>>, _> = "B,a,b,".parse();
+        assert!(set.as_ref().unwrap().0.iter().eq(["b", "a"]));
+        let set = Set::<_, Trailing<Upper<Comma>>>::from(set.unwrap().0);
+        assert_eq!(set.to_string(), "B,A,");
+
+        let map: Result<Map<String, String, Upper<Comma>>, _> = "k=v,".parse();
+        assert_eq!(map.as_ref().unwrap().0["K"], "V");
+        let map = Map::<_, _, Trailing<Lower<Semicolon>>>::from(map.unwrap().0);
+        assert_eq!(map.to_string(), "k=v;");
+    }
+}
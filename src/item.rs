@@ -0,0 +1,443 @@
+//! Element wrappers that adjust how each segment is parsed or formatted.
+//!
+//! They are meant to be used as the element type of a list, e.g.
+//! `CS<Nfc<String>>`, and dereference to the wrapped value.
+
+use crate::separator::Case;
+use crate::Error;
+
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! item_wrapper {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            #[inline]
+            fn from(v: T) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<T> std::ops::Deref for $name<T> {
+            type Target = T;
+
+            #[inline]
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> std::ops::DerefMut for $name<T> {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    };
+}
+
+item_wrapper! {
+    /// Lowercases each segment before parsing and the formatted output of each
+    /// element, so differently cased inputs share one canonical form.
+    ///
+    /// [`separator::Lower`](crate::separator::Lower) does the same for every
+    /// element of a list.
+    Lowercase
+}
+
+item_wrapper! {
+    /// Uppercases each segment before parsing and the formatted output of each
+    /// element, so differently cased inputs share one canonical form.
+    ///
+    /// [`separator::Upper`](crate::separator::Upper) does the same for every
+    /// element of a list.
+    Uppercase
+}
+
+macro_rules! impl_case {
+    ($($name:ident => $case:expr),*) => {$(
+        impl<T: FromStr> FromStr for $name<T> {
+            type Err = T::Err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $case.apply(s).parse().map(Self)
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                $case.fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+impl_case!(Lowercase => Case::Lower, Uppercase => Case::Upper);
+
+/// An integer restricted to `MIN..=MAX`, checked when parsing.
+///
+/// ```rust
+/// use serde_cs::item::Bounded;
+/// use serde_cs::vec::CS;
+///
+/// type Port = Bounded<u32, 1, 65535>;
+///
+/// let ports: Result<CS<Port>, _> = serde_json::from_str(r#""80,443""#);
+/// assert!(ports.is_ok());
+///
+/// let ports: Result<CS<Port>, _> = "80,70000".parse();
+/// assert_eq!(ports.unwrap_err().to_string(), "value out of range 1..=65535");
+/// ```
+///
+/// Bounds with `MIN > MAX` are rejected at compile time:
+///
+/// ```rust,compile_fail
+/// let n = serde_cs::item::Bounded::<u32, 5, 1>::new(3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(T);
+
+struct Bounds<const MIN: i128, const MAX: i128>;
+
+impl<const MIN: i128, const MAX: i128> Bounds<MIN, MAX> {
+    const VALID: () = assert!(MIN <= MAX, "Bounded needs MIN <= MAX");
+}
+
+impl<T, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX>
+where
+    T: TryInto<i128> + Copy,
+{
+    /// Returns `None` when `v` lies outside the bounds.
+    pub fn new(v: T) -> Option<Self> {
+        let () = Bounds::<MIN, MAX>::VALID;
+
+        match v.try_into() {
+            Ok(n) if (MIN..=MAX).contains(&n) => Some(Self(v)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> std::ops::Deref for Bounded<T, MIN, MAX> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> FromStr for Bounded<T, MIN, MAX>
+where
+    T: FromStr + TryInto<i128> + Copy,
+{
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.parse()?).ok_or(Error::OutOfRange { min: MIN, max: MAX })
+    }
+}
+
+impl<T: fmt::Display, const MIN: i128, const MAX: i128> fmt::Display for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+item_wrapper! {
+    /// A number written with a decimal comma, as in `"1,5"`, for lists
+    /// separated by something other than `,`.
+    ///
+    /// ```rust
+    /// use serde_cs::item::DecimalComma;
+    /// use serde_cs::separator::Semicolon;
+    /// use serde_cs::vec::CS;
+    ///
+    /// let cs: CS<DecimalComma<f64>, Semicolon> = "1,5;2,25;3".parse().unwrap();
+    /// assert_eq!(cs.0, [DecimalComma(1.5), DecimalComma(2.25), DecimalComma(3.0)]);
+    /// assert_eq!(cs.to_string(), "1,5;2,25;3");
+    /// ```
+    DecimalComma
+}
+
+impl<T: FromStr> FromStr for DecimalComma<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.replace(',', ".").parse().map(Self)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DecimalComma<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_string().replace('.', ","))
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+mod normalization {
+    use unicode_normalization::UnicodeNormalization;
+
+    use std::fmt;
+    use std::str::FromStr;
+
+    item_wrapper! {
+        /// Applies Unicode NFC normalization to each segment before parsing.
+        Nfc
+    }
+
+    item_wrapper! {
+        /// Applies Unicode NFKC normalization to each segment before parsing.
+        Nfkc
+    }
+
+    impl<T: FromStr> FromStr for Nfc<T> {
+        type Err = T::Err;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.nfc().collect::<String>().parse().map(Self)
+        }
+    }
+
+    impl<T: FromStr> FromStr for Nfkc<T> {
+        type Err = T::Err;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.nfkc().collect::<String>().parse().map(Self)
+        }
+    }
+
+    impl<T: fmt::Display> fmt::Display for Nfc<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    impl<T: fmt::Display> fmt::Display for Nfkc<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+pub use normalization::{Nfc, Nfkc};
+
+#[cfg(feature = "percent-encoding")]
+mod percent {
+    use crate::Error;
+
+    use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Controls, space, `%` and the URL reserved characters, which covers
+    /// every built-in ASCII separator.
+    const RESERVED: &AsciiSet = &CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'#')
+        .add(b'%')
+        .add(b'&')
+        .add(b'\'')
+        .add(b'+')
+        .add(b',')
+        .add(b'/')
+        .add(b':')
+        .add(b';')
+        .add(b'<')
+        .add(b'=')
+        .add(b'>')
+        .add(b'?')
+        .add(b'@')
+        .add(b'[')
+        .add(b'\\')
+        .add(b']')
+        .add(b'^')
+        .add(b'`')
+        .add(b'{')
+        .add(b'|')
+        .add(b'}');
+
+    item_wrapper! {
+        /// Percent-decodes each segment before parsing and percent-encodes
+        /// reserved characters in the formatted output, so any text survives
+        /// a round trip.
+        PercentEncoded
+    }
+
+    impl<T: FromStr> FromStr for PercentEncoded<T> {
+        type Err = Error<T::Err>;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let decoded = percent_decode_str(s)
+                .decode_utf8()
+                .map_err(|_| Error::InvalidUtf8)?;
+            Ok(Self(decoded.parse()?))
+        }
+    }
+
+    impl<T: fmt::Display> fmt::Display for PercentEncoded<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            utf8_percent_encode(&self.0.to_string(), RESERVED).fmt(f)
+        }
+    }
+}
+
+#[cfg(feature = "percent-encoding")]
+pub use percent::PercentEncoded;
+
+#[cfg(test)]
+mod tests {
+    use super::Lowercase;
+    use crate::vec::CS;
+
+    #[derive(Debug, PartialEq)]
+    struct Upper(String);
+
+    impl std::fmt::Display for Upper {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.0.to_uppercase())
+        }
+    }
+
+    #[test]
+    fn lowercase() {
+        let CS(tags, _): CS<Lowercase<String>> = "Rust,RUST,rust,ÄÖ".parse().unwrap();
+        assert_eq!(
+            tags,
+            ["rust", "rust", "rust", "äö"].map(|s| Lowercase(s.to_string()))
+        );
+
+        let s = serde_json::to_string(&CS::new(vec![Lowercase(Upper("a".into()))]));
+        assert_eq!(s.unwrap(), r#""a""#);
+
+        let cs: Result<CS<Lowercase<bool>>, _> = serde_json::from_str(r#""TRUE,False""#);
+        assert_eq!(cs.unwrap().0, [Lowercase(true), Lowercase(false)]);
+    }
+
+    #[test]
+    fn uppercase() {
+        use super::Uppercase;
+
+        let CS(tags, _): CS<Uppercase<String>> = "Rust,RUST,rust,äö".parse().unwrap();
+        assert_eq!(
+            tags,
+            ["RUST", "RUST", "RUST", "ÄÖ"].map(|s| Uppercase(s.to_string()))
+        );
+
+        let s = serde_json::to_string(&CS::new(vec![Uppercase("get"), Uppercase("Post")]));
+        assert_eq!(s.ok().as_deref(), Some(r#""GET,POST""#));
+    }
+
+    #[test]
+    fn bounded() {
+        use super::Bounded;
+        use crate::Error;
+
+        type Port = Bounded<u16, 1, 65535>;
+        let CS(ports, _): CS<Port> = "80,443,65535".parse().unwrap();
+        assert_eq!(
+            ports.iter().map(|p| **p).collect::<Vec<_>>(),
+            [80, 443, 65535]
+        );
+
+        let res: Result<CS<Port>, _> = "80,0".parse();
+        assert_eq!(res.unwrap_err(), Error::OutOfRange { min: 1, max: 65535 });
+        assert!(matches!("70000".parse::<Port>(), Err(Error::Parse(_))));
+
+        type Small = Bounded<i64, -5, 5>;
+        assert!("-5".parse::<Small>().is_ok());
+        assert!("-6".parse::<Small>().is_err());
+        assert!(Bounded::<u128, 0, 10>::new(u128::MAX).is_none());
+        assert_eq!(Small::new(3).map(|v| v.to_string()), Some("3".to_string()));
+    }
+
+    #[test]
+    fn decimal_comma() {
+        use super::DecimalComma;
+        use crate::separator::Semicolon;
+
+        let cs: Result<CS<DecimalComma<f64>, Semicolon>, _> =
+            serde_json::from_str(r#""1,5;-0,25;;2""#);
+        assert!(
+            matches!(cs, Ok(CS(v, _)) if v == [DecimalComma(1.5), DecimalComma(-0.25), DecimalComma(2.0)])
+        );
+
+        let cs = CS::<_, Semicolon>::from(vec![DecimalComma(1.5), DecimalComma(10.0)]);
+        assert_eq!(cs.to_string(), "1,5;10");
+        assert!("1,5".parse::<DecimalComma<u32>>().is_err());
+        assert!("1.5".parse::<DecimalComma<f64>>().is_ok());
+    }
+
+    #[cfg(feature = "percent-encoding")]
+    #[test]
+    fn percent_encoded() {
+        use super::PercentEncoded;
+        use crate::separator::Pipe;
+        use crate::Error;
+
+        let cs = CS::new(vec![
+            PercentEncoded("a,b".to_string()),
+            PercentEncoded("50% off".into()),
+        ]);
+        assert_eq!(cs.to_string(), "a%2Cb,50%25%20off");
+        assert_eq!(PercentEncoded("x|y;é").to_string(), "x%7Cy%3B%C3%A9");
+
+        let CS(tags, _): CS<PercentEncoded<String>> = "a%2Cb,%C3%A9,plain".parse().unwrap();
+        assert_eq!(
+            tags,
+            ["a,b", "é", "plain"].map(|s| PercentEncoded(s.to_string()))
+        );
+
+        let cs: Result<CS<PercentEncoded<String>, Pipe>, _> = "%7C|%FF".parse();
+        assert_eq!(cs.unwrap_err(), Error::InvalidUtf8);
+        let cs: Result<CS<PercentEncoded<u32>>, _> = serde_json::from_str(r#""%31%32,3""#);
+        assert_eq!(cs.unwrap().0, [PercentEncoded(12), PercentEncoded(3)]);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn normalization() {
+        use super::{Nfc, Nfkc};
+
+        let CS(tags, _): CS<Nfc<String>> = "cafe\u{301},caf\u{e9}".parse().unwrap();
+        assert_eq!(tags[0], tags[1]);
+        assert_eq!(*tags[0], "caf\u{e9}");
+
+        let CS(tags, _): CS<Nfkc<String>> = "\u{fb01}le,\u{ff11}".parse().unwrap();
+        assert_eq!(*tags[0], "file");
+        assert_eq!(*tags[1], "1");
+
+        let CS(nums, _): CS<Nfkc<u32>> = "\u{ff11}\u{ff12},3".parse().unwrap();
+        assert_eq!(nums, [Nfkc(12), Nfkc(3)]);
+        assert!("\u{ff41}".parse::<CS<Nfkc<u32>>>().is_err());
+
+        let s = serde_json::to_string(&CS::new(vec![Nfc("a".to_string()), Nfc("b".to_string())]));
+        assert_eq!(s.unwrap(), r#""a,b""#);
+    }
+}
@@ -5,49 +5,202 @@
 //! ## Serialization
 //!
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! use serde_cs::vec::CS;
 //! type Csu32 = CS<u32>;
 //!
-//! let cs: Csu32 = CS(vec![]);
+//! let cs: Csu32 = CS::new(vec![]);
 //! let s = serde_json::to_string(&cs).unwrap();
 //! assert_eq!(s, r#""""#);
 //!
-//! let cs: Csu32 = CS(vec![1]);
+//! let cs: Csu32 = CS::new(vec![1]);
 //! let s = serde_json::to_string(&cs).unwrap();
 //! assert_eq!(s, r#""1""#);
 //!
-//! let cs: Csu32 = CS(vec![1, 2, 3]);
+//! let cs: Csu32 = CS::new(vec![1, 2, 3]);
 //! let s = serde_json::to_string(&cs).unwrap();
 //! assert_eq!(s, r#""1,2,3""#);
+//! # }
 //! ```
 //!
 //!
 //! ## Deserialization
 //!
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! use serde_cs::vec::CS;
 //! type Csu32 = CS<u32>;
 //!
 //! let s = r#""""#;
-//! let CS(cs): Csu32 = serde_json::from_str(s).unwrap();
+//! let CS(cs, _): Csu32 = serde_json::from_str(s).unwrap();
 //! assert_eq!(cs, vec![0u32; 0]);
 //!
 //! let s = r#"",,,,""#;
-//! let CS(cs): Csu32 = serde_json::from_str(s).unwrap();
+//! let CS(cs, _): Csu32 = serde_json::from_str(s).unwrap();
 //! assert_eq!(cs, vec![0u32; 0]);
 //!
 //! let s = r#"",,1,,,,,""#;
-//! let CS(cs): Csu32 = serde_json::from_str(s).unwrap();
+//! let CS(cs, _): Csu32 = serde_json::from_str(s).unwrap();
 //! assert_eq!(cs, vec![1]);
 //!
 //! let s = r#"",,1,,,2,,,,""#;
-//! let CS(cs): Csu32 = serde_json::from_str(s).unwrap();
+//! let CS(cs, _): Csu32 = serde_json::from_str(s).unwrap();
 //! assert_eq!(cs, vec![1, 2]);
 //!
 //! let s = r#"",,1,,,a,,,,""#;
 //! let res: Result<Csu32, _> = serde_json::from_str(s);
 //! assert!(res.is_err());
+//! # }
 //! ```
+//!
+//! ## `no_std`
+//!
+//! Without the default `std` feature, the crate builds without the standard
+//! library or an allocator. Only [`separator`], [`Error`] and, with the
+//! `heapless` feature, `heapless::CS` are available then.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "std")]
+pub mod adapt;
+#[cfg(feature = "allocator-api2")]
+pub mod allocator;
+#[cfg(feature = "std")]
 pub mod array;
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "std")]
+pub mod borrowed;
+#[cfg(feature = "std")]
+pub mod bounded;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "std")]
+pub mod collect;
+#[cfg(feature = "compact_str")]
+pub mod compact;
+#[cfg(feature = "std")]
+pub mod compat;
+#[cfg(feature = "std")]
+pub mod cow;
+#[cfg(feature = "std")]
+pub mod deque;
+#[cfg(feature = "std")]
+pub mod diff;
+#[cfg(feature = "encoding")]
+pub mod encoding;
+mod error;
+#[cfg(feature = "std")]
+pub mod escaped;
+#[cfg(feature = "std")]
+pub mod expr;
+#[cfg(feature = "std")]
+pub mod field_mask;
+#[cfg(feature = "std")]
+pub mod filter;
+#[cfg(feature = "std")]
+pub mod fixed;
+#[cfg(feature = "std")]
+pub mod formatted;
+#[cfg(feature = "std")]
+pub mod geo;
+#[cfg(feature = "std")]
+pub mod grouped;
+#[cfg(feature = "std")]
+pub mod heap;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+#[cfg(feature = "indexmap")]
+pub mod indexmap;
+#[cfg(feature = "std")]
+pub mod item;
+#[cfg(feature = "std")]
+pub mod line;
+#[cfg(feature = "std")]
+pub mod map;
+#[cfg(feature = "std")]
+pub mod matrix;
+#[cfg(feature = "std")]
+pub mod merge;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "std")]
+pub mod nested;
+#[cfg(feature = "std")]
+mod ops;
+#[cfg(feature = "std")]
+pub mod parser;
+#[cfg(feature = "std")]
+pub mod patch;
+#[cfg(feature = "std")]
+pub mod path;
+#[cfg(feature = "polars")]
+pub mod polars;
+#[cfg(feature = "std")]
+pub mod protocols;
+#[cfg(feature = "std")]
+pub mod quoted;
+#[cfg(feature = "std")]
+pub mod range;
+#[cfg(feature = "std")]
+pub mod ranged;
+#[cfg(feature = "std")]
+pub mod read;
+#[cfg(feature = "std")]
+pub mod recover;
+#[cfg(feature = "std")]
+pub mod rle;
+pub mod separator;
+#[cfg(feature = "std")]
+pub mod set;
+#[cfg(feature = "std")]
+pub mod shared;
+#[cfg(feature = "std")]
+pub mod shell;
+#[cfg(feature = "std")]
+pub mod slots;
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
+#[cfg(feature = "std")]
+pub mod sort;
+#[cfg(feature = "std")]
+pub mod sparse;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod strict;
+#[cfg(feature = "std")]
+pub mod tab;
+#[cfg(feature = "std")]
+pub mod transcode;
+#[cfg(feature = "std")]
+pub mod tuple;
+#[cfg(feature = "std")]
+pub mod unique;
+#[cfg(feature = "std")]
 pub mod vec;
+#[cfg(feature = "std")]
+pub mod view;
+#[cfg(feature = "std")]
+pub mod warnings;
+#[cfg(feature = "std")]
+pub mod whitespace;
+#[cfg(feature = "std")]
+pub mod wildcard;
+#[cfg(feature = "std")]
+mod with_parser;
+#[cfg(feature = "std")]
+pub mod zip;
+
+pub use error::Error;
+#[cfg(feature = "std")]
+pub use ops::Numeric;
+#[cfg(feature = "std")]
+pub use with_parser::deserialize_with_parser;
@@ -0,0 +1,61 @@
+//! Line separated lists, for newline-delimited values embedded in one string.
+//!
+//! Both `\n` and `\r\n` are accepted when parsing; the separator type picks
+//! the line ending written back.
+//!
+//! ```rust
+//! use serde_cs::line::LS;
+//! use serde_cs::separator::CrLf;
+//!
+//! let hosts: LS<String> = serde_json::from_str(r#""a.example\r\nb.example\n\n""#).unwrap();
+//! assert_eq!(hosts.to_string(), "a.example\nb.example");
+//!
+//! let hosts: LS<String, CrLf> = hosts.into_inner().into();
+//! assert_eq!(hosts.to_string(), "a.example\r\nb.example");
+//! ```
+
+use crate::separator::Lf;
+use crate::vec::CS;
+
+pub type LS<T, E = Lf> = CS<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::LS;
+    use crate::separator::CrLf;
+    use crate::vec::CS;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let ls: Result<LS<u32>, _> = s.parse();
+        assert!(matches!(ls, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str("\n\r\n", vec![]);
+        assert_ok_from_str("1\n2", vec![1, 2]);
+        assert_ok_from_str("1\r\n\r\n2\r\n", vec![1, 2]);
+
+        let ls: Result<LS<u32>, _> = "1,2".parse();
+        assert!(ls.is_err());
+        let ls: Result<LS<u32>, _> = "1\r\r\n".parse();
+        assert!(ls.is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(LS::<u32>::default().to_string(), "");
+        assert_eq!(LS::<_>::from(vec![1, 2]).to_string(), "1\n2");
+        assert_eq!(LS::<_, CrLf>::from(vec![1, 2]).to_string(), "1\r\n2");
+    }
+
+    #[test]
+    fn serde() {
+        let ls: Result<LS<String, CrLf>, _> = serde_json::from_str(r#""a\nb""#);
+        assert_eq!(ls.unwrap().0, ["a", "b"]);
+
+        let s = serde_json::to_string(&LS::<_, CrLf>::from(vec!["a", "b"]));
+        assert_eq!(s.unwrap(), r#""a\r\nb""#);
+    }
+}
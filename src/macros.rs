@@ -0,0 +1,182 @@
+//! Crate-internal helpers for the list wrapper newtypes.
+
+/// Implements the listed traits and accessors for a newtype over `$inner`,
+/// optionally followed by a `PhantomData` marker field.
+///
+/// ```text
+/// list_wrapper! {
+///     impl[T, S] CS[T, S](Vec<T>, PhantomData):
+///         Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut,
+///         Serialize, Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+/// }
+/// ```
+///
+/// Trait impls are bounded on `$inner` (or on `Self` for the serde ones), so
+/// they hold exactly when the wrapped collection or [`FromStr`] /
+/// [`Display`] impl does. The optional `[..]` in `Deserialize` adds bounds
+/// that its `expecting` message needs. Structs with named fields omit the
+/// `(..)` and may only list `Serialize` and `Deserialize`; types without
+/// generics pass empty `[]`.
+///
+/// [`FromStr`]: core::str::FromStr
+/// [`Display`]: core::fmt::Display
+// Not every feature set has a wrapper that uses it.
+#[allow(unused_macros)]
+macro_rules! list_wrapper {
+    (
+        impl $g:tt $name:ident $p:tt ($inner:ty, PhantomData):
+        $($tr:ident $([$($t:tt)*])? $(($($e:tt)*))?),* $(,)?
+    ) => {
+        $(list_wrapper!(
+            @$tr $([$($t)*])? $(($($e)*))? $g $name $p ($inner) {, ::core::marker::PhantomData}
+        );)*
+    };
+    (
+        impl $g:tt $name:ident $p:tt ($inner:ty):
+        $($tr:ident $([$($t:tt)*])? $(($($e:tt)*))?),* $(,)?
+    ) => {
+        $(list_wrapper!(@$tr $([$($t)*])? $(($($e)*))? $g $name $p ($inner) {});)*
+    };
+    (
+        impl $g:tt $name:ident $p:tt:
+        $($tr:ident $([$($t:tt)*])? $(($($e:tt)*))?),* $(,)?
+    ) => {
+        $(list_wrapper!(@$tr $([$($t)*])? $(($($e)*))? $g $name $p (()) {});)*
+    };
+    (@Debug [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::fmt::Debug for $name<$($p)*>
+        where
+            $inner: ::core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+    };
+    (@Clone [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::clone::Clone for $name<$($p)*>
+        where
+            $inner: ::core::clone::Clone,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self(self.0.clone() $($m)*)
+            }
+        }
+    };
+    (@PartialEq [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::cmp::PartialEq for $name<$($p)*>
+        where
+            $inner: ::core::cmp::PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+    };
+    (@Eq [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::cmp::Eq for $name<$($p)*> where $inner: ::core::cmp::Eq {}
+    };
+    (@Default [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::default::Default for $name<$($p)*>
+        where
+            $inner: ::core::default::Default,
+        {
+            #[inline]
+            fn default() -> Self {
+                Self(::core::default::Default::default() $($m)*)
+            }
+        }
+    };
+    (@AsRef [$t:ty] [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::convert::AsRef<[$t]> for $name<$($p)*> {
+            #[inline]
+            fn as_ref(&self) -> &[$t] {
+                &self.0
+            }
+        }
+    };
+    (@From [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::core::convert::From<$inner> for $name<$($p)*> {
+            #[inline]
+            fn from(v: $inner) -> Self {
+                Self(v $($m)*)
+            }
+        }
+    };
+    (@Inner [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> $name<$($p)*> {
+            #[inline]
+            pub fn into_inner(self) -> $inner {
+                self.0
+            }
+
+            #[inline]
+            pub fn to_inner(&self) -> &$inner {
+                &self.0
+            }
+        }
+    };
+    (@InnerMut [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> $name<$($p)*> {
+            #[inline]
+            pub fn to_inner_mut(&mut self) -> &mut $inner {
+                &mut self.0
+            }
+        }
+    };
+    (@Serialize [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}) => {
+        impl<$($g)*> ::serde::ser::Serialize for $name<$($p)*>
+        where
+            Self: ::core::fmt::Display,
+        {
+            fn serialize<Ser>(&self, serializer: Ser) -> ::core::result::Result<Ser::Ok, Ser::Error>
+            where
+                Ser: ::serde::ser::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+    };
+    (
+        @Deserialize ($([$($w:tt)*])? |$f:ident| $expecting:expr)
+        [$($g:tt)*] $name:ident [$($p:tt)*] ($inner:ty) {$($m:tt)*}
+    ) => {
+        impl<'de, $($g)*> ::serde::de::Deserialize<'de> for $name<$($p)*>
+        where
+            $($($w)*,)?
+            Self: ::core::str::FromStr,
+            <Self as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        {
+            fn deserialize<De>(deserializer: De) -> ::core::result::Result<Self, De::Error>
+            where
+                De: ::serde::de::Deserializer<'de>,
+            {
+                struct Visitor<$($g)*>(::core::marker::PhantomData<$name<$($p)*>>);
+
+                impl<'de, $($g)*> ::serde::de::Visitor<'de> for Visitor<$($p)*>
+                where
+                    $($($w)*,)?
+                    $name<$($p)*>: ::core::str::FromStr,
+                    <$name<$($p)*> as ::core::str::FromStr>::Err: ::core::fmt::Display,
+                {
+                    type Value = $name<$($p)*>;
+
+                    fn expecting(&self, $f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        $expecting
+                    }
+
+                    fn visit_str<Er>(self, values: &str) -> ::core::result::Result<Self::Value, Er>
+                    where
+                        Er: ::serde::de::Error,
+                    {
+                        values.parse().map_err(::serde::de::Error::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor(::core::marker::PhantomData))
+            }
+        }
+    };
+}
@@ -0,0 +1,431 @@
+//! Key–value lists such as `"a=1,b=2"`, parsed into a [`BTreeMap`].
+//!
+//! Keys and values both go through `FromStr` and `Display`, and everything
+//! after the first key–value delimiter belongs to the value. Formatting
+//! follows the map's key order.
+//!
+//! ```rust
+//! use serde_cs::map::CS;
+//!
+//! let cs: CS<String, u32> = serde_json::from_str(r#""b=2,a=1""#).unwrap();
+//! assert_eq!(cs.0["a"], 1);
+//! assert_eq!(serde_json::to_string(&cs).unwrap(), r#""a=1,b=2""#);
+//! ```
+//!
+//! A key given more than once keeps its last value unless another
+//! [`DuplicateKeys`] policy is chosen; [`Multi`] keeps every value instead.
+//!
+//! The delimiter between a key and its value is chosen independently from the
+//! one between entries:
+//!
+//! ```rust
+//! use serde_cs::map::{Colon, FatArrow, CS};
+//! use serde_cs::separator::{Comma, Spaced};
+//!
+//! let cs: CS<String, u32, Spaced<Comma>, Colon> = "a:1,b:2".parse().unwrap();
+//! assert_eq!(cs.to_string(), "a:1, b:2");
+//!
+//! let cs: CS<String, u32, Comma, FatArrow> = "a=>1,b=>2".parse().unwrap();
+//! assert_eq!(cs.0["b"], 2);
+//! ```
+//!
+//! ```rust
+//! use serde_cs::map::{Equals, Multi, Reject, CS};
+//! use serde_cs::separator::Comma;
+//! use serde_cs::Error;
+//!
+//! let cs: Result<CS<String, u32, Comma, Equals, Reject>, _> = "a=1,a=2".parse();
+//! assert!(matches!(cs, Err(Error::Duplicate)));
+//!
+//! let multi: Multi<String, u32> = "a=1,b=3,a=2".parse().unwrap();
+//! assert_eq!(multi.0["a"], [1, 2]);
+//! assert_eq!(multi.to_string(), "a=1,a=2,b=3");
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use std::collections::{btree_map, BTreeMap};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{error, fmt};
+
+/// Whether the key or the value of an entry failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryError<K, V> {
+    Key(K),
+    Value(V),
+}
+
+impl<K: fmt::Display, V: fmt::Display> fmt::Display for EntryError<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Key(e) => e.fmt(f),
+            Self::Value(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<K, V> error::Error for EntryError<K, V>
+where
+    K: error::Error + 'static,
+    V: error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Key(e) => Some(e),
+            Self::Value(e) => Some(e),
+        }
+    }
+}
+
+crate::separator!(
+    /// `=`
+    pub Equals,
+    "="
+);
+crate::separator!(
+    /// `:`
+    pub Colon,
+    ":"
+);
+crate::separator!(
+    /// `=>`
+    pub FatArrow,
+    "=>"
+);
+
+/// What to do when a key appears more than once.
+pub trait DuplicateKeys {
+    /// Inserts an entry, returning `false` if the input must be rejected.
+    fn insert<K: Ord, V>(map: &mut BTreeMap<K, V>, key: K, value: V) -> bool;
+}
+
+/// Later values replace earlier ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LastWins;
+
+impl DuplicateKeys for LastWins {
+    #[inline]
+    fn insert<K: Ord, V>(map: &mut BTreeMap<K, V>, key: K, value: V) -> bool {
+        map.insert(key, value);
+        true
+    }
+}
+
+/// Later values are ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FirstWins;
+
+impl DuplicateKeys for FirstWins {
+    #[inline]
+    fn insert<K: Ord, V>(map: &mut BTreeMap<K, V>, key: K, value: V) -> bool {
+        map.entry(key).or_insert(value);
+        true
+    }
+}
+
+/// Repeated keys fail with [`Error::Duplicate`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Reject;
+
+impl DuplicateKeys for Reject {
+    #[inline]
+    fn insert<K: Ord, V>(map: &mut BTreeMap<K, V>, key: K, value: V) -> bool {
+        match map.entry(key) {
+            btree_map::Entry::Vacant(e) => {
+                e.insert(value);
+                true
+            }
+            btree_map::Entry::Occupied(_) => false,
+        }
+    }
+}
+
+pub struct CS<K, V, S = Comma, D = Equals, P = LastWins>(
+    pub BTreeMap<K, V>,
+    pub PhantomData<(S, D, P)>,
+);
+
+list_wrapper! {
+    impl[K, V, S, D, P] CS[K, V, S, D, P](BTreeMap<K, V>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([D: Separator, S: Separator] |f| {
+            write!(f, "key{}value pairs separated by {:?}", D::SEP, S::SEP)
+        })
+}
+
+pub(crate) type EntryResult<K, V> =
+    Result<(K, V), Error<EntryError<<K as FromStr>::Err, <V as FromStr>::Err>>>;
+
+pub(crate) fn parse_entry<K: FromStr, V: FromStr>(s: &str, delimiter: &str) -> EntryResult<K, V> {
+    let (key, value) = s.split_once(delimiter).ok_or(Error::MissingDelimiter)?;
+    let key = key.parse().map_err(EntryError::Key)?;
+    let value = value.parse().map_err(EntryError::Value)?;
+    Ok((key, value))
+}
+
+impl<K, V, S, D, P> FromStr for CS<K, V, S, D, P>
+where
+    K: FromStr + Ord,
+    V: FromStr,
+    S: Separator,
+    D: Separator,
+    P: DuplicateKeys,
+{
+    type Err = Error<EntryError<K::Err, V::Err>>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = BTreeMap::new();
+        for s in S::split(s).filter(|s| !s.is_empty()) {
+            let (key, value) = parse_entry(&S::CASE.apply(s), D::SEP)?;
+            if !P::insert(&mut map, key, value) {
+                return Err(Error::Duplicate);
+            }
+        }
+        Ok(Self::from(map))
+    }
+}
+
+impl<K, V, S, D, P> IntoIterator for CS<K, V, S, D, P> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V, S, D, P> fmt::Display for CS<K, V, S, D, P>
+where
+    K: fmt::Display,
+    V: fmt::Display,
+    S: Separator,
+    D: Separator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (k, v)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(&format_args!("{}{}{}", k, D::SEP, v), f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A key–value list keeping every value of a repeated key, in input order.
+pub struct Multi<K, V, S = Comma, D = Equals>(pub BTreeMap<K, Vec<V>>, pub PhantomData<(S, D)>);
+
+list_wrapper! {
+    impl[K, V, S, D] Multi[K, V, S, D](BTreeMap<K, Vec<V>>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([D: Separator, S: Separator] |f| {
+            write!(f, "key{}value pairs separated by {:?}", D::SEP, S::SEP)
+        })
+}
+
+impl<K, V, S, D> FromStr for Multi<K, V, S, D>
+where
+    K: FromStr + Ord,
+    V: FromStr,
+    S: Separator,
+    D: Separator,
+{
+    type Err = Error<EntryError<K::Err, V::Err>>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map: BTreeMap<K, Vec<V>> = BTreeMap::new();
+        for s in S::split(s).filter(|s| !s.is_empty()) {
+            let (key, value) = parse_entry(&S::CASE.apply(s), D::SEP)?;
+            map.entry(key).or_default().push(value);
+        }
+        Ok(Self::from(map))
+    }
+}
+
+impl<K, V, S, D> IntoIterator for Multi<K, V, S, D> {
+    type Item = (K, Vec<V>);
+    type IntoIter = btree_map::IntoIter<K, Vec<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V, S, D> fmt::Display for Multi<K, V, S, D>
+where
+    K: fmt::Display,
+    V: fmt::Display,
+    S: Separator,
+    D: Separator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = self
+            .0
+            .iter()
+            .flat_map(|(k, vs)| vs.iter().map(move |v| (k, v)));
+        for (i, (k, v)) in entries.enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(&format_args!("{}{}{}", k, D::SEP, v), f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Colon, EntryError, Equals, FatArrow, FirstWins, LastWins, Multi, Reject, CS};
+    use crate::separator::{Comma, Lower, Semicolon, Spaced, Trailing, Trim, Upper};
+    use crate::Error;
+
+    use std::collections::BTreeMap;
+
+    type MapTest = CS<String, u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<(&str, u32)>) {
+        let expected: BTreeMap<_, _> = expected
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let cs: Result<MapTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("a=1", vec![("a", 1)]);
+        assert_ok_from_str("b=2,,a=1", vec![("a", 1), ("b", 2)]);
+        assert_ok_from_str("a=1,a=2", vec![("a", 2)]);
+        assert_ok_from_str("=3", vec![("", 3)]);
+
+        let cs: Result<MapTest, _> = "a=1,b".parse();
+        assert_eq!(cs.unwrap_err(), Error::MissingDelimiter);
+        let cs: Result<MapTest, _> = "a=x".parse();
+        assert!(matches!(cs, Err(Error::Parse(EntryError::Value(_)))));
+        let cs: Result<CS<u32, u32>, _> = "a=1".parse();
+        assert!(matches!(cs, Err(Error::Parse(EntryError::Key(_)))));
+
+        let cs: Result<CS<String, String>, _> = "url=a=b".parse();
+        assert_eq!(cs.unwrap().0["url"], "a=b");
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(MapTest::default().to_string(), "");
+
+        let cs: MapTest = "c=3,a=1,b=2".parse().unwrap();
+        assert_eq!(cs.to_string(), "a=1,b=2,c=3");
+        assert_eq!(format!("{:#}", cs), "a=1, b=2, c=3");
+
+        let cs = CS::<_, _, Semicolon>::from(BTreeMap::from([("x", 1), ("y", 2)]));
+        assert_eq!(cs.to_string(), "x=1;y=2");
+    }
+
+    #[test]
+    fn separator_policy() {
+        let cs: Result<CS<String, String, Lower<Comma>>, _> = "Host=DB,".parse();
+        assert_eq!(cs.unwrap().0["host"], "db");
+
+        let cs = CS::<_, _, Trailing<Upper<Comma>>>::from(BTreeMap::from([("a", "x")]));
+        assert_eq!(cs.to_string(), "A=X,");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String, String, Semicolon>, _> =
+            serde_json::from_str(r#""host=db;port=5432""#);
+        let v = cs.unwrap().0;
+        assert_eq!(v["host"], "db");
+        assert_eq!(v["port"], "5432");
+
+        let cs: MapTest = "b=1,a=2".parse().unwrap();
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""a=2,b=1""#);
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let cs: Result<CS<String, u32, Comma, Equals, LastWins>, _> = "a=1,b=2,a=3".parse();
+        assert_eq!(cs.unwrap().0["a"], 3);
+        let cs: Result<CS<String, u32, Comma, Equals, FirstWins>, _> = "a=1,b=2,a=3".parse();
+        let v = cs.unwrap().0;
+        assert_eq!(v["a"], 1);
+        assert_eq!(v["b"], 2);
+
+        let cs: Result<CS<String, u32, Comma, Equals, Reject>, _> = "a=1,b=2,a=3".parse();
+        assert_eq!(cs.unwrap_err(), Error::Duplicate);
+        let cs: Result<CS<String, u32, Comma, Equals, Reject>, _> =
+            serde_json::from_str(r#""a=1,b=2""#);
+        assert_eq!(cs.unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn multi() {
+        let multi: Result<Multi<String, u32>, _> = "a=1,b=2,,a=3".parse();
+        let v = &multi.as_ref().unwrap().0;
+        assert_eq!(v["a"], [1, 3]);
+        assert_eq!(v["b"], [2]);
+        assert_eq!(multi.unwrap().to_string(), "a=1,a=3,b=2");
+
+        let multi: Result<Multi<String, u32>, _> = "a=1,a".parse();
+        assert_eq!(multi.unwrap_err(), Error::MissingDelimiter);
+
+        let multi: Result<Multi<String, String, Semicolon>, _> =
+            serde_json::from_str(r#""accept=text/html;accept=*/*""#);
+        assert_eq!(multi.unwrap().0["accept"], ["text/html", "*/*"]);
+        assert_eq!(Multi::<u32, u32>::default().to_string(), "");
+    }
+
+    #[test]
+    fn multi_separator_policy() {
+        let multi: Result<Multi<String, String, Upper<Semicolon>>, _> = "a=x;A=y;".parse();
+        assert_eq!(multi.unwrap().0["A"], ["X", "Y"]);
+
+        let multi =
+            Multi::<_, _, Trailing<Lower<Comma>>>::from(BTreeMap::from([("K", vec![1, 2])]));
+        assert_eq!(multi.to_string(), "k=1,k=2,");
+    }
+
+    #[test]
+    fn key_value_delimiter() {
+        let cs: Result<CS<String, u32, Trim<Comma>, Colon>, _> = "a:1, b :2".parse();
+        let v = &cs.as_ref().unwrap().0;
+        assert_eq!(v["a"], 1);
+        assert_eq!(v["b "], 2);
+
+        let cs: Result<CS<String, u32, Spaced<Comma>, Colon>, _> =
+            serde_json::from_str(r#""a:1, b:2""#);
+        let v = &cs.as_ref().unwrap().0;
+        assert_eq!(v["a"], 1);
+        assert_eq!(v["b"], 2);
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""a:1, b:2""#);
+
+        let cs: Result<CS<String, String, Comma, FatArrow>, _> = "a=>x=y,b=>=>".parse();
+        let v = &cs.as_ref().unwrap().0;
+        assert_eq!(v["a"], "x=y");
+        assert_eq!(v["b"], "=>");
+        let cs: Result<CS<String, u32, Comma, FatArrow>, _> = "a=1".parse();
+        assert_eq!(cs.unwrap_err(), Error::MissingDelimiter);
+
+        let multi = Multi::<_, _, Semicolon, Colon>::from(std::collections::BTreeMap::from([(
+            "k",
+            vec![1, 2],
+        )]));
+        assert_eq!(multi.to_string(), "k:1;k:2");
+    }
+}
@@ -0,0 +1,154 @@
+//! Row-major matrices, with rows separated by `;` and columns by `,`.
+//!
+//! ```rust
+//! use serde_cs::matrix::Matrix;
+//! use serde_cs::Error;
+//!
+//! let m: Matrix<u32> = serde_json::from_str(r#""1,2,3;4,5,6""#).unwrap();
+//! assert_eq!((m.rows(), m.cols()), (2, 3));
+//! assert_eq!(m.to_string(), "1,2,3;4,5,6");
+//!
+//! assert_eq!("1,2;3".parse::<Matrix<u32>>(), Err(Error::Ragged));
+//! ```
+//!
+//! Rows of different lengths are rejected; use
+//! [`Nested`](crate::nested::Nested) for ragged input.
+
+use crate::nested::Nested;
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Matrix<T>(Vec<Vec<T>>);
+
+list_wrapper! {
+    impl[T] Matrix[T](Vec<Vec<T>>):
+        AsRef[Vec<T>], Inner, Serialize,
+        Deserialize(|f| f.write_str("rows separated by ';' of values separated by ','"))
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = Error<std::convert::Infallible>;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Self::new(rows).ok_or(Error::Ragged)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Returns `None` unless every row has the same length.
+    pub fn new(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        rows.iter()
+            .all(|row| row.len() == cols)
+            .then_some(Self(rows))
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.0.first().map_or(0, Vec::len)
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.0.get(row)?.get(col)
+    }
+}
+
+impl<T: FromStr> FromStr for Matrix<T> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s.parse::<Nested<T>>()?.into_vecs();
+        Self::new(rows).ok_or(Error::Ragged)
+    }
+}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = Vec<T>;
+    type IntoIter = vec::IntoIter<Vec<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(";")?;
+            }
+            for (j, v) in row.iter().enumerate() {
+                if j > 0 {
+                    f.write_str(",")?;
+                }
+                v.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::Error;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<Vec<u32>>) {
+        let m: Result<Matrix<u32>, _> = s.parse();
+        assert!(matches!(m, Ok(v) if *v.to_inner() == expected))
+    }
+
+    fn assert_err_from_str(s: &str) {
+        let m: Result<Matrix<u32>, _> = s.parse();
+        assert!(matches!(m, Err(Error::Ragged)))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str("1", vec![vec![1]]);
+        assert_ok_from_str("1,2;3,4", vec![vec![1, 2], vec![3, 4]]);
+        assert_ok_from_str("1;2;3", vec![vec![1], vec![2], vec![3]]);
+        assert_ok_from_str("1,2;;3,4;", vec![vec![1, 2], vec![3, 4]]);
+
+        assert_err_from_str("1,2;3");
+        assert_err_from_str("1;2,3");
+        assert!(matches!("1,a".parse::<Matrix<u32>>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn shape() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let shape = m.as_ref().map(|m| (m.rows(), m.cols()));
+        assert_eq!(shape, Some((2, 3)));
+        assert_eq!(m.as_ref().and_then(|m| m.get(1, 2)), Some(&6));
+        assert_eq!(m.as_ref().and_then(|m| m.get(2, 0)), None);
+
+        assert!(Matrix::new(vec![vec![1], vec![]]).is_none());
+        assert_eq!(
+            Matrix::try_from(vec![vec![1], vec![2, 3]]).unwrap_err(),
+            Error::Ragged
+        );
+        assert_eq!(Matrix::<u32>::default().cols(), 0);
+    }
+
+    #[test]
+    fn serde() {
+        let m: Result<Matrix<f64>, _> = serde_json::from_str(r#""1.5,2;3,4""#);
+        assert_eq!(*m.unwrap().to_inner(), [vec![1.5, 2.0], vec![3.0, 4.0]]);
+
+        let m = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+        let s = serde_json::to_string(&m);
+        assert_eq!(s.unwrap(), r#""1,2;3,4""#);
+    }
+}
@@ -0,0 +1,288 @@
+//! Lists assembled from several comma separated strings.
+//!
+//! Formats such as query strings may repeat a key (`?tag=a,b&tag=c`) and hand
+//! the values over as a sequence. [`MergingCS`] accepts either a single string
+//! or a sequence of strings and concatenates their elements.
+//!
+//! Telling the two apart needs a self-describing format. Formats that are not
+//! human readable, such as bincode or postcard, are read as the single string
+//! that [`MergingCS`] serializes to.
+//!
+//! ```rust
+//! use serde_cs::merge::{Dedup, MergingCS};
+//!
+//! let tags: MergingCS<String> = serde_json::from_str(r#"["a,b", "c,a"]"#).unwrap();
+//! assert_eq!(tags.0, vec!["a", "b", "c", "a"]);
+//!
+//! let tags: MergingCS<String, Dedup> = serde_json::from_str(r#"["a,b", "c,a"]"#).unwrap();
+//! assert_eq!(tags.0, vec!["a", "b", "c"]);
+//! ```
+
+use serde::de;
+
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// Whether merged elements equal to an earlier one are dropped.
+pub trait DedupPolicy<T> {
+    /// Removes from `v` every element equal to an earlier one, if this
+    /// policy drops them.
+    fn dedup(v: &mut Vec<T>);
+}
+
+/// Keeps every element.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeepAll;
+
+impl<T> DedupPolicy<T> for KeepAll {
+    #[inline]
+    fn dedup(_v: &mut Vec<T>) {}
+}
+
+/// Keeps only the first occurrence of each element.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Dedup;
+
+impl<T: Hash + Eq> DedupPolicy<T> for Dedup {
+    fn dedup(v: &mut Vec<T>) {
+        let mut seen = HashSet::with_capacity(v.len());
+        let mut keep: vec::IntoIter<bool> = v
+            .iter()
+            .map(|v| seen.insert(v))
+            .collect::<Vec<_>>()
+            .into_iter();
+        v.retain(|_| keep.next().unwrap_or(true));
+    }
+}
+
+pub struct MergingCS<T, D = KeepAll>(pub Vec<T>, PhantomData<D>);
+
+list_wrapper! {
+    impl[T, D] MergingCS[T, D](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], Inner, Serialize
+}
+
+impl<T, D> MergingCS<T, D> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, D: DedupPolicy<T>> MergingCS<T, D> {
+    pub fn push(&mut self, value: T) {
+        self.extend(Some(value));
+    }
+
+    /// Parses `s` and appends its elements. Nothing is appended if any of
+    /// them fails to parse.
+    pub fn merge_str(&mut self, s: &str) -> Result<(), T::Err>
+    where
+        T: FromStr,
+    {
+        let values = s
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .map(T::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.extend(values);
+        Ok(())
+    }
+}
+
+impl<T, D: DedupPolicy<T>> Extend<T> for MergingCS<T, D> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+        D::dedup(&mut self.0);
+    }
+}
+
+impl<T, D: DedupPolicy<T>> From<Vec<T>> for MergingCS<T, D> {
+    fn from(v: Vec<T>) -> Self {
+        let mut merged = Self::new();
+        merged.extend(v);
+        merged
+    }
+}
+
+impl<T: FromStr, D: DedupPolicy<T>> FromStr for MergingCS<T, D> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut merged = Self::new();
+        merged.merge_str(s)?;
+        Ok(merged)
+    }
+}
+
+impl<T, D> IntoIterator for MergingCS<T, D> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, D> fmt::Display for MergingCS<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de, T, D> de::Deserialize<'de> for MergingCS<T, D>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: DedupPolicy<T>,
+{
+    fn deserialize<D2>(deserializer: D2) -> Result<Self, D2::Error>
+    where
+        D2: de::Deserializer<'de>,
+    {
+        struct MergingVisitor<T, D>(PhantomData<(T, D)>);
+
+        impl<'de, T, D> de::Visitor<'de> for MergingVisitor<T, D>
+        where
+            T: FromStr,
+            T::Err: fmt::Display,
+            D: DedupPolicy<T>,
+        {
+            type Value = MergingCS<T, D>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("comma separated list or sequence of them")
+            }
+
+            fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                values.parse().map_err(de::Error::custom)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut merged = MergingCS::new();
+                while let Some(values) = seq.next_element::<std::borrow::Cow<'de, str>>()? {
+                    merged.merge_str(&values).map_err(de::Error::custom)?;
+                }
+                Ok(merged)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(MergingVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_str(MergingVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dedup, MergingCS};
+    type MergingTest = MergingCS<u32>;
+    type DedupTest = MergingCS<u32, Dedup>;
+
+    #[test]
+    fn merge_str() {
+        let mut merged = MergingTest::new();
+        assert!(merged.merge_str("1,2").is_ok());
+        assert!(merged.merge_str(",,2,3").is_ok());
+        assert_eq!(merged.0, [1, 2, 2, 3]);
+        assert!(merged.merge_str("4,a").is_err());
+
+        let mut merged = DedupTest::new();
+        assert!(merged.merge_str("1,2,1").is_ok());
+        assert!(merged.merge_str("3,2").is_ok());
+        assert_eq!(merged.0, [1, 2, 3]);
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(DedupTest::from(vec![2, 1, 2]).0, [2, 1]);
+        assert_eq!(MergingTest::from(vec![2, 1, 2]).0, [2, 1, 2]);
+    }
+
+    #[test]
+    fn deserialize() {
+        let merged: Result<MergingTest, _> = serde_json::from_str(r#""1,2,1""#);
+        assert_eq!(merged.unwrap().0, [1, 2, 1]);
+
+        let merged: Result<MergingTest, _> = serde_json::from_str(r#"["1,2", "", "1"]"#);
+        assert_eq!(merged.unwrap().0, [1, 2, 1]);
+
+        let merged: Result<DedupTest, _> = serde_json::from_str(r#"["1,2", "1,3"]"#);
+        assert_eq!(merged.unwrap().0, [1, 2, 3]);
+
+        let merged: Result<MergingTest, _> = serde_json::from_str(r#"["1", 2]"#);
+        assert!(merged.is_err());
+        let merged: Result<MergingTest, _> = serde_json::from_str(r#"["1", "a"]"#);
+        assert!(merged.is_err());
+    }
+
+    #[test]
+    fn dedup_many() {
+        let merged = DedupTest::from((0..100_000).map(|i| i % 1000).collect::<Vec<_>>());
+        assert_eq!(merged.0, (0..1000).collect::<Vec<_>>());
+
+        let mut merged = DedupTest::from(vec![3, 1]);
+        assert!(merged.merge_str("2,1,3,4").is_ok());
+        assert_eq!(merged.0, [3, 1, 2, 4]);
+        assert!(merged.merge_str("5,a").is_err());
+        assert_eq!(merged.0, [3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn not_human_readable() {
+        use serde::de::{self, Deserialize, Visitor};
+
+        struct Compact<'a>(&'a str);
+
+        impl<'de> de::Deserializer<'de> for Compact<'de> {
+            type Error = de::value::Error;
+
+            fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+                Err(de::Error::custom("not self-describing"))
+            }
+
+            fn deserialize_str<V: Visitor<'de>>(self, v: V) -> Result<V::Value, Self::Error> {
+                v.visit_borrowed_str(self.0)
+            }
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char string
+                bytes byte_buf option unit unit_struct newtype_struct seq tuple
+                tuple_struct map struct enum identifier ignored_any
+            }
+        }
+
+        let merged = MergingTest::deserialize(Compact("1,2,1"));
+        assert_eq!(merged, Ok(MergingCS::from(vec![1, 2, 1])));
+    }
+
+    #[test]
+    fn serialize() {
+        let s = serde_json::to_string(&MergingTest::from(vec![1, 2]));
+        assert_eq!(s.unwrap(), r#""1,2""#);
+    }
+}
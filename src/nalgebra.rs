@@ -0,0 +1,34 @@
+use crate::array::CS;
+
+use nalgebra::{SVector, Scalar};
+
+impl<T: Scalar, const N: usize> From<CS<T, N>> for SVector<T, N> {
+    #[inline]
+    fn from(cs: CS<T, N>) -> Self {
+        Self::from(cs.0)
+    }
+}
+
+impl<T: Scalar, const N: usize> From<SVector<T, N>> for CS<T, N> {
+    #[inline]
+    fn from(v: SVector<T, N>) -> Self {
+        Self::new(v.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::CS;
+
+    use nalgebra::{SVector, Vector3};
+
+    #[test]
+    fn svector() {
+        let cs: CS<f64, 3> = "1,2.5,-3".parse().unwrap();
+        let v = SVector::from(cs);
+        assert_eq!(v, Vector3::new(1.0, 2.5, -3.0));
+
+        let cs = CS::from(v * 2.0);
+        assert_eq!(cs.to_string(), "2,5,-6");
+    }
+}
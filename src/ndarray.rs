@@ -0,0 +1,65 @@
+use crate::matrix::Matrix;
+use crate::vec::CS;
+
+use ndarray::{Array1, Array2};
+
+impl<T, S> CS<T, S> {
+    /// Moves the elements into a one-dimensional array without copying them.
+    #[inline]
+    pub fn into_array1(self) -> Array1<T> {
+        Array1::from_vec(self.0)
+    }
+}
+
+impl<T, S> From<Array1<T>> for CS<T, S> {
+    #[inline]
+    fn from(array: Array1<T>) -> Self {
+        Self::from(array.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Moves the elements into a two-dimensional array.
+    pub fn into_array2(self) -> Array2<T> {
+        let shape = (self.rows(), self.cols());
+        let elements = self.into_inner().into_iter().flatten().collect();
+        Array2::from_shape_vec(shape, elements).expect("matrix rows have equal length")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::vec::CS;
+
+    use ndarray::{array, Array1, Array2};
+
+    #[test]
+    fn into_array1() {
+        let cs: CS<f64> = "1.5,2,-3".parse().unwrap();
+        assert_eq!(cs.into_array1(), array![1.5, 2.0, -3.0]);
+        assert_eq!(
+            CS::<u32>::new(vec![]).into_array1(),
+            Array1::<u32>::zeros(0)
+        );
+    }
+
+    #[test]
+    fn from_array1() {
+        assert_eq!(CS::from(array![1, 2]), CS::new(vec![1, 2]));
+        assert_eq!(
+            CS::from(array![1, 2, 3].slice_move(ndarray::s![..;2])),
+            CS::new(vec![1, 3])
+        );
+    }
+
+    #[test]
+    fn into_array2() {
+        let m: Matrix<u32> = "1,2,3;4,5,6".parse().unwrap();
+        assert_eq!(m.into_array2(), array![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            Matrix::<u32>::default().into_array2(),
+            Array2::<u32>::zeros((0, 0))
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Lists of lists, split first by an outer and then by an inner separator.
+//!
+//! ```rust
+//! use serde_cs::nested::Nested;
+//!
+//! let rows: Nested<u32> = serde_json::from_str(r#""1,2;3,4;5""#).unwrap();
+//! assert_eq!(rows.to_string(), "1,2;3,4;5");
+//! assert_eq!(rows.into_vecs(), vec![vec![1, 2], vec![3, 4], vec![5]]);
+//! ```
+//!
+//! Any [`vec::CS`] whose element is itself a [`vec::CS`] with a different
+//! separator nests the same way; [`Nested`] only names the common case.
+
+use crate::separator::{Comma, Semicolon};
+use crate::vec;
+
+/// Groups separated by `Outer`, each holding elements separated by `Inner`.
+pub type Nested<T, Outer = Semicolon, Inner = Comma> = vec::CS<vec::CS<T, Inner>, Outer>;
+
+impl<T, Outer, Inner> vec::CS<vec::CS<T, Inner>, Outer> {
+    /// Unwraps both levels into plain vectors.
+    pub fn into_vecs(self) -> Vec<Vec<T>> {
+        self.0.into_iter().map(vec::CS::into_inner).collect()
+    }
+}
+
+impl<T, Outer, Inner> From<Vec<Vec<T>>> for vec::CS<vec::CS<T, Inner>, Outer> {
+    #[inline]
+    fn from(v: Vec<Vec<T>>) -> Self {
+        Self::with_separator(v.into_iter().map(vec::CS::with_separator).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nested;
+    use crate::separator::{Comma, Pipe};
+
+    #[test]
+    fn from_str() {
+        let rows: Result<Nested<u32>, _> = "1,2;3,,4;;5;".parse();
+        assert!(
+            matches!(rows.map(Nested::into_vecs), Ok(v) if v == [vec![1, 2], vec![3, 4], vec![5]])
+        );
+
+        let rows: Result<Nested<u32>, _> = ",;1".parse();
+        assert_eq!(rows.map(Nested::into_vecs).unwrap(), [vec![], vec![1]]);
+
+        assert!("1,a;2".parse::<Nested<u32>>().is_err());
+    }
+
+    #[test]
+    fn serde() {
+        let rows: Result<Nested<String, Pipe, Comma>, _> = serde_json::from_str(r#""a,b|c""#);
+        assert_eq!(
+            rows.map(Nested::into_vecs).unwrap(),
+            [vec!["a", "b"], vec!["c"]]
+        );
+
+        let rows = Nested::<u32>::from(vec![vec![1, 2], vec![3]]);
+        let s = serde_json::to_string(&rows);
+        assert_eq!(s.unwrap(), r#""1,2;3""#);
+    }
+}
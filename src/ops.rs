@@ -0,0 +1,264 @@
+use crate::vec::CS;
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Numbers that [`CS::sum`] and [`CS::mean`] aggregate.
+pub trait Numeric: Copy {
+    const ZERO: Self;
+
+    /// `self + rhs`, or `None` on overflow. Floats never overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// The nearest `f64`, which may lose precision for large integers.
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_numeric {
+    (int: $($i:ty),*; float: $($f:ty),*) => {
+        $(
+            impl Numeric for $i {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$i>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+        $(
+            impl Numeric for $f {
+                const ZERO: Self = 0.0;
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+
+                #[inline]
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(
+    int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    float: f32, f64
+);
+
+impl<T: Eq + Hash + Clone, S> CS<T, S> {
+    /// Elements of `self` followed by those of `other` not already present, without duplicates.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut seen = HashSet::new();
+        self.0
+            .iter()
+            .chain(&other.0)
+            .filter(|v| seen.insert(*v))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Elements of `self` also found in `other`, without duplicates.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let other: HashSet<&T> = other.0.iter().collect();
+        self.retain_unique(|v| other.contains(v))
+    }
+
+    /// Elements of `self` not found in `other`, without duplicates.
+    pub fn difference(&self, other: &Self) -> Self {
+        let other: HashSet<&T> = other.0.iter().collect();
+        self.retain_unique(|v| !other.contains(v))
+    }
+
+    fn retain_unique(&self, mut f: impl FnMut(&T) -> bool) -> Self {
+        let mut seen = HashSet::new();
+        self.0
+            .iter()
+            .filter(|v| f(v) && seen.insert(*v))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+/// Lookups for lists kept in ascending order; results are unspecified otherwise.
+impl<T: Ord, S> CS<T, S> {
+    #[inline]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.0.binary_search(x)
+    }
+
+    #[inline]
+    pub fn contains_sorted(&self, x: &T) -> bool {
+        self.binary_search(x).is_ok()
+    }
+
+    /// Inserts `x` keeping the list sorted and returns its index.
+    pub fn insert_sorted(&mut self, x: T) -> usize {
+        let (Ok(i) | Err(i)) = self.binary_search(&x);
+        self.0.insert(i, x);
+        i
+    }
+}
+
+impl<T, S> CS<T, S> {
+    /// The sum of all elements, or `None` if it overflows `T`.
+    pub fn sum(&self) -> Option<T>
+    where
+        T: Numeric,
+    {
+        self.0
+            .iter()
+            .try_fold(T::ZERO, |sum, v| sum.checked_add(*v))
+    }
+
+    /// The smallest element, or the first one among incomparable elements such as NaN.
+    pub fn min(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.0.iter().reduce(|a, b| if b < a { b } else { a })
+    }
+
+    /// The largest element, or the first one among incomparable elements such as NaN.
+    pub fn max(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.0.iter().reduce(|a, b| if b > a { b } else { a })
+    }
+
+    /// The arithmetic mean, or `None` for an empty list.
+    pub fn mean(&self) -> Option<f64>
+    where
+        T: Numeric,
+    {
+        if self.0.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.0.iter().map(|v| v.as_f64()).sum();
+        Some(sum / self.0.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vec::CS;
+
+    #[test]
+    fn union() {
+        assert_eq!(
+            CS::<u32>::new(vec![]).union(&CS::new(vec![])),
+            CS::new(vec![])
+        );
+        assert_eq!(
+            CS::new(vec![3, 1]).union(&CS::new(vec![2, 1])),
+            CS::new(vec![3, 1, 2])
+        );
+        assert_eq!(
+            CS::new(vec![1, 1]).union(&CS::new(vec![2, 2])),
+            CS::new(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        assert_eq!(
+            CS::new(vec![1, 2]).intersection(&CS::new(vec![])),
+            CS::new(vec![])
+        );
+        assert_eq!(
+            CS::new(vec![3, 1, 2]).intersection(&CS::new(vec![2, 3])),
+            CS::new(vec![3, 2])
+        );
+        assert_eq!(
+            CS::new(vec![1, 1, 2]).intersection(&CS::new(vec![1])),
+            CS::new(vec![1])
+        );
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(
+            CS::new(vec![1, 2]).difference(&CS::new(vec![])),
+            CS::new(vec![1, 2])
+        );
+        assert_eq!(
+            CS::new(vec![3, 1, 2]).difference(&CS::new(vec![1])),
+            CS::new(vec![3, 2])
+        );
+        assert_eq!(
+            CS::new(vec![2, 2, 1]).difference(&CS::new(vec![1])),
+            CS::new(vec![2])
+        );
+    }
+
+    #[test]
+    fn binary_search() {
+        let cs = CS::new(vec![1, 3, 5]);
+        assert_eq!(cs.binary_search(&3), Ok(1));
+        assert_eq!(cs.binary_search(&4), Err(2));
+
+        assert!(cs.contains_sorted(&5));
+        assert!(!cs.contains_sorted(&0));
+        assert!(!CS::new(vec![]).contains_sorted(&0));
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut cs = CS::new(vec![]);
+        assert_eq!(cs.insert_sorted(3), 0);
+        assert_eq!(cs.insert_sorted(1), 0);
+        assert_eq!(cs.insert_sorted(5), 2);
+        assert_eq!(cs.insert_sorted(4), 2);
+        assert_eq!(cs, CS::new(vec![1, 3, 4, 5]));
+    }
+
+    #[test]
+    fn stats() {
+        let cs: CS<u32> = "3,1,4,1,5".parse().unwrap();
+        assert_eq!(cs.sum(), Some(14));
+        assert_eq!(cs.min(), Some(&1));
+        assert_eq!(cs.max(), Some(&5));
+        assert_eq!(cs.mean(), Some(2.8));
+
+        let empty = CS::<f32>::new(vec![]);
+        assert_eq!(empty.sum(), Some(0.0));
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.mean(), None);
+
+        let cs = CS::new(vec![-1.5, f64::NAN, 2.5]);
+        assert_eq!(cs.min(), Some(&-1.5));
+        assert_eq!(cs.max(), Some(&2.5));
+        assert_eq!(cs.mean().map(f64::is_nan), Some(true));
+    }
+
+    #[test]
+    fn wide_integers() {
+        let cs: CS<u64> = "18446744073709551615,1".parse().unwrap();
+        assert_eq!(cs.sum(), None);
+        assert_eq!(cs.mean(), Some(9223372036854775808.0));
+
+        let cs = CS::new(vec![i64::MIN, -1]);
+        assert_eq!(cs.sum(), None);
+        let cs = CS::new(vec![i64::MAX, i64::MIN]);
+        assert_eq!(cs.sum(), Some(-1));
+
+        let cs: CS<usize> = "1,2,3,4".parse().unwrap();
+        assert_eq!(cs.sum(), Some(10));
+        assert_eq!(cs.mean(), Some(2.5));
+        let cs = CS::new(vec![i128::MAX, 1]);
+        assert_eq!(cs.sum(), None);
+        assert_eq!(CS::new(vec![u8::MAX, 1]).sum(), None);
+    }
+}
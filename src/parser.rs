@@ -0,0 +1,183 @@
+//! Push parser for comma separated input arriving in arbitrary chunks.
+//!
+//! ```rust
+//! use serde_cs::parser::CsParser;
+//!
+//! let mut parser = CsParser::<u32>::new();
+//! let first: Vec<_> = parser.feed(b"1,2").collect();
+//! assert_eq!(first, vec![Ok(1)]);
+//!
+//! let second: Vec<_> = parser.feed(b"3,,4").collect();
+//! assert_eq!(second, vec![Ok(23)]);
+//!
+//! let rest: Vec<_> = parser.finish().collect();
+//! assert_eq!(rest, vec![Ok(4)]);
+//! ```
+
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Splits comma separated input arriving in arbitrary chunks into segments.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Splitter {
+    buf: Vec<u8>,
+    start: usize,
+}
+
+impl Splitter {
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        if self.start > 0 {
+            self.buf.drain(..self.start);
+            self.start = 0;
+        }
+        self.buf.extend_from_slice(chunk);
+    }
+
+    /// Returns the next complete, non-empty segment.
+    pub(crate) fn next_segment(&mut self) -> Option<&[u8]> {
+        loop {
+            let rest = &self.buf[self.start..];
+            let end = rest.iter().position(|&b| b == b',')?;
+            let start = self.start;
+            self.start += end + 1;
+            if end > 0 {
+                return Some(&self.buf[start..start + end]);
+            }
+        }
+    }
+
+    /// Returns the trailing segment once the input has ended.
+    pub(crate) fn finish(&mut self) -> Option<&[u8]> {
+        let start = std::mem::replace(&mut self.start, self.buf.len());
+        let rest = &self.buf[start..];
+        (!rest.is_empty()).then_some(rest)
+    }
+}
+
+/// Incremental parser that emits elements as soon as they are complete.
+#[derive(Debug, Clone)]
+pub struct CsParser<T> {
+    splitter: Splitter,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for CsParser<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            splitter: Splitter::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr> CsParser<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `chunk` and iterates over the elements completed so far.
+    /// Elements left unconsumed are yielded again by the next call.
+    pub fn feed(&mut self, chunk: &[u8]) -> Parsed<'_, T> {
+        self.splitter.push(chunk);
+        Parsed {
+            splitter: &mut self.splitter,
+            finished: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Ends the input and iterates over the remaining elements, including the
+    /// trailing one. The parser can be reused for new input afterwards.
+    pub fn finish(&mut self) -> Parsed<'_, T> {
+        Parsed {
+            splitter: &mut self.splitter,
+            finished: true,
+            _marker: PhantomData,
+        }
+    }
+}
+
+pub struct Parsed<'a, T> {
+    splitter: &'a mut Splitter,
+    finished: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromStr> Iterator for Parsed<'_, T> {
+    type Item = Result<T, Error<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let segment = match self.splitter.next_segment() {
+            Some(segment) => segment,
+            None if self.finished => self.splitter.finish()?,
+            None => return None,
+        };
+
+        let parsed = match std::str::from_utf8(segment) {
+            Ok(s) => s.parse().map_err(Error::Parse),
+            Err(_) => Err(Error::InvalidUtf8),
+        };
+        Some(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CsParser;
+    use crate::Error;
+
+    fn assert_ok_chunks(chunks: &[&str], expected: Vec<u32>) {
+        let mut parser = CsParser::<u32>::new();
+        let mut items = Vec::new();
+        for chunk in chunks {
+            items.extend(parser.feed(chunk.as_bytes()));
+        }
+        items.extend(parser.finish());
+
+        assert_eq!(items, expected.into_iter().map(Ok).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn feed() {
+        assert_ok_chunks(&[], vec![]);
+        assert_ok_chunks(&["", ",,", ""], vec![]);
+        assert_ok_chunks(&["1,2,3"], vec![1, 2, 3]);
+        assert_ok_chunks(&["1", "2,", ",3", "4"], vec![12, 34]);
+        assert_ok_chunks(&["1", ",", "2", ",", ""], vec![1, 2]);
+    }
+
+    #[test]
+    fn emits_complete_elements() {
+        let mut parser = CsParser::<u32>::new();
+        assert_eq!(parser.feed(b"1").count(), 0);
+        assert_eq!(parser.feed(b"0,2").collect::<Vec<_>>(), [Ok(10)]);
+        assert_eq!(parser.finish().collect::<Vec<_>>(), [Ok(2)]);
+        assert_eq!(parser.finish().count(), 0);
+    }
+
+    #[test]
+    fn unconsumed_elements_are_kept() {
+        let mut parser = CsParser::<u32>::new();
+        assert_eq!(parser.feed(b"1,2,").next(), Some(Ok(1)));
+        assert_eq!(parser.feed(b"3").collect::<Vec<_>>(), [Ok(2)]);
+        assert_eq!(parser.finish().collect::<Vec<_>>(), [Ok(3)]);
+    }
+
+    #[test]
+    fn errors() {
+        let mut parser = CsParser::<u32>::new();
+        let items: Vec<_> = parser.feed(b"a,\xe3\x81").collect();
+        assert!(matches!(items[..], [Err(Error::Parse(_))]));
+
+        parser.feed(b"\x82,\xff");
+        let items: Vec<_> = parser.finish().collect();
+        assert!(matches!(
+            items[..],
+            [Err(Error::Parse(_)), Err(Error::InvalidUtf8)]
+        ));
+    }
+}
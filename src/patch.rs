@@ -0,0 +1,188 @@
+//! Incremental updates to a list, written as `+`/`-` prefixed elements.
+//!
+//! ```rust
+//! use serde_cs::patch::CsPatch;
+//! use serde_cs::vec::CS;
+//!
+//! let patch: CsPatch<String> = serde_json::from_str(r#""+admin,-guest,+editor""#).unwrap();
+//!
+//! let mut roles: CS<String> = "guest,admin".parse().unwrap();
+//! patch.apply(&mut roles);
+//! assert_eq!(roles.to_string(), "admin,editor");
+//! ```
+
+use crate::vec::CS;
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op<T> {
+    Add(T),
+    Remove(T),
+}
+
+impl<T: FromStr> FromStr for Op<T> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(v) = s.strip_prefix('+') {
+            Ok(Self::Add(v.parse()?))
+        } else if let Some(v) = s.strip_prefix('-') {
+            Ok(Self::Remove(v.parse()?))
+        } else {
+            Err(Error::MissingPrefix)
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Op<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Add(v) => write!(f, "+{}", v),
+            Self::Remove(v) => write!(f, "-{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsPatch<T>(pub Vec<Op<T>>);
+
+list_wrapper! {
+    impl[T] CsPatch[T](Vec<Op<T>>):
+        Default, From, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of +/- prefixed values"))
+}
+
+impl<T> CsPatch<T> {
+    #[inline]
+    pub fn into_inner(self) -> Vec<Op<T>> {
+        self.0
+    }
+
+    /// Applies the operations in order. Adding an element already present and
+    /// removing an absent one are no-ops; removing drops every occurrence.
+    pub fn apply<S>(&self, cs: &mut CS<T, S>)
+    where
+        T: PartialEq + Clone,
+    {
+        for op in &self.0 {
+            match op {
+                Op::Add(v) if !cs.0.contains(v) => cs.0.push(v.clone()),
+                Op::Add(_) => {}
+                Op::Remove(v) => cs.0.retain(|x| x != v),
+            }
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for CsPatch<T> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(Op::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<T> IntoIterator for CsPatch<T> {
+    type Item = Op<T>;
+    type IntoIter = vec::IntoIter<Op<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CsPatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <Op<T> as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CsPatch, Op};
+    use crate::vec::CS;
+    use crate::Error;
+    type PatchTest = CsPatch<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<Op<u32>>) {
+        let patch: Result<PatchTest, _> = s.parse();
+        assert!(matches!(patch, Ok(v) if v == CsPatch(expected)))
+    }
+
+    fn assert_err_from_str(s: &str) {
+        let patch: Result<PatchTest, _> = s.parse();
+        assert!(patch.is_err())
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("+1", vec![Op::Add(1)]);
+        assert_ok_from_str(",+1,,-2,", vec![Op::Add(1), Op::Remove(2)]);
+
+        assert_err_from_str("1");
+        assert_err_from_str("+1,2");
+        assert_err_from_str("+a");
+        assert_err_from_str("+");
+
+        let patch: Result<PatchTest, _> = "1".parse();
+        assert_eq!(patch.unwrap_err(), Error::MissingPrefix);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(PatchTest::default().to_string(), "");
+        assert_eq!(CsPatch(vec![Op::Add(1)]).to_string(), "+1");
+        assert_eq!(
+            CsPatch(vec![Op::Add(1), Op::Remove(2)]).to_string(),
+            "+1,-2"
+        );
+    }
+
+    fn assert_apply(patch: &str, cs: Vec<u32>, expected: Vec<u32>) {
+        let patch: PatchTest = patch.parse().unwrap();
+        let mut cs = CS::new(cs);
+        patch.apply(&mut cs);
+        assert_eq!(cs, CS::new(expected));
+    }
+
+    #[test]
+    fn apply() {
+        assert_apply("", vec![1, 2], vec![1, 2]);
+        assert_apply("+3", vec![1, 2], vec![1, 2, 3]);
+        assert_apply("+2", vec![1, 2], vec![1, 2]);
+        assert_apply("-1", vec![1, 2, 1], vec![2]);
+        assert_apply("-5", vec![1, 2], vec![1, 2]);
+        assert_apply("+3,-3", vec![1], vec![1]);
+        assert_apply("-1,+1", vec![1, 2], vec![2, 1]);
+    }
+
+    #[test]
+    fn serde() {
+        let patch: Result<PatchTest, _> = serde_json::from_str(r#""+1,-2""#);
+        assert_eq!(patch.unwrap(), CsPatch(vec![Op::Add(1), Op::Remove(2)]));
+
+        let patch: Result<PatchTest, _> = serde_json::from_str(r#""1""#);
+        assert!(patch.is_err());
+
+        let s = serde_json::to_string(&CsPatch(vec![Op::Remove(1), Op::Add(2)]));
+        assert_eq!(s.unwrap(), r#""-1,+2""#);
+    }
+}
@@ -0,0 +1,135 @@
+//! `PATH`-style lists, separated by `:` on Unix and `;` on Windows.
+//!
+//! ```rust
+//! use serde_cs::path::{PathList, SEPARATOR};
+//! use std::path::PathBuf;
+//!
+//! let s = format!(r#""/usr/bin{0}{0}/bin""#, SEPARATOR);
+//! let paths: PathList = serde_json::from_str(&s).unwrap();
+//! assert_eq!(paths.0, vec![PathBuf::from("/usr/bin"), PathBuf::from("/bin")]);
+//! ```
+
+use serde::ser;
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// The separator used by `std::env::split_paths` on this platform.
+pub const SEPARATOR: char = if cfg!(windows) { ';' } else { ':' };
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathList<T = PathBuf>(pub Vec<T>);
+
+list_wrapper! {
+    impl[T] PathList[T](Vec<T>):
+        Default, AsRef[T], From, Inner, InnerMut,
+        Deserialize(|f| write!(f, "list of paths separated by {:?}", SEPARATOR))
+}
+
+impl<T: FromStr> FromStr for PathList<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(SEPARATOR)
+            .filter(|s| !s.is_empty())
+            .map(T::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<T> IntoIterator for PathList<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Joins the paths, replacing invalid UTF-8 like [`Path::display`].
+impl<T: AsRef<Path>> fmt::Display for PathList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            write!(f, "{}", v.as_ref().display())?;
+        }
+
+        for v in it {
+            write!(f, "{}{}", SEPARATOR, v.as_ref().display())?
+        }
+
+        Ok(())
+    }
+}
+
+/// Fails on paths that are not valid UTF-8 or that contain [`SEPARATOR`].
+impl<T: AsRef<Path>> ser::Serialize for PathList<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        for v in &self.0 {
+            match v.as_ref().to_str() {
+                Some(s) if s.contains(SEPARATOR) => {
+                    return Err(ser::Error::custom(format!(
+                        "path contains separator {:?}",
+                        SEPARATOR
+                    )))
+                }
+                Some(_) => {}
+                None => return Err(ser::Error::custom("path is not valid UTF-8")),
+            }
+        }
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathList, SEPARATOR};
+
+    use std::path::PathBuf;
+
+    fn join(parts: &[&str]) -> String {
+        parts.join(&SEPARATOR.to_string())
+    }
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let paths: Result<PathList, _> = s.parse();
+        let expected: Vec<PathBuf> = expected.into_iter().map(PathBuf::from).collect();
+        assert!(matches!(paths, Ok(PathList(v)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(&join(&["", ""]), vec![]);
+        assert_ok_from_str("a", vec!["a"]);
+        assert_ok_from_str(&join(&["a", "", "b c", ""]), vec!["a", "b c"]);
+
+        assert_eq!(
+            std::env::split_paths(&join(&["x", "y"])).collect::<Vec<_>>(),
+            join(&["x", "y"]).parse::<PathList>().unwrap().0
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(PathList::<PathBuf>::default().to_string(), "");
+        assert_eq!(PathList(vec!["a", "b"]).to_string(), join(&["a", "b"]));
+    }
+
+    #[test]
+    fn serde() {
+        let s = serde_json::to_string(&PathList(vec!["a", "b"]));
+        assert_eq!(s.unwrap(), format!("{:?}", join(&["a", "b"])));
+
+        let s = serde_json::to_string(&PathList(vec![join(&["a", "b"])]));
+        assert!(s.is_err());
+
+        let paths: Result<PathList<String>, _> = serde_json::from_str(r#""a""#);
+        assert_eq!(paths.unwrap().0, ["a"]);
+    }
+}
@@ -0,0 +1,104 @@
+//! Conversions from comma separated lists into polars series.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use serde_cs::vec::CS;
+//!
+//! let ids: CS<i64> = "1,2,3".parse().unwrap();
+//! let s = ids.into_series("ids");
+//! assert_eq!(s.len(), 3);
+//!
+//! let raw = StringChunked::new("tags".into(), &[Some("1,2"), None, Some("3")]);
+//! let tags = serde_cs::polars::split_column::<i64>(&raw).unwrap();
+//! assert_eq!(tags.len(), 3);
+//! assert_eq!(tags.null_count(), 1);
+//! ```
+
+use crate::vec::CS;
+use crate::Error;
+
+use polars::prelude::{ListChunked, NamedFrom, Series, StringChunked};
+
+use std::str::FromStr;
+
+impl<T> CS<T> {
+    #[inline]
+    pub fn into_series(self, name: &str) -> Series
+    where
+        Series: NamedFrom<Vec<T>, [T]>,
+    {
+        Series::new(name.into(), self.0)
+    }
+}
+
+/// Builds a list column holding one list per record.
+pub fn to_list_chunked<T, I>(name: &str, records: I) -> ListChunked
+where
+    I: IntoIterator<Item = CS<T>>,
+    Series: NamedFrom<Vec<T>, [T]>,
+{
+    records
+        .into_iter()
+        .map(|cs| Some(cs.into_series("")))
+        .collect::<ListChunked>()
+        .with_name(name.into())
+}
+
+/// Parses every string of `column` as a comma separated list of `T`, keeping
+/// the column name. Null strings become null lists.
+pub fn split_column<T>(column: &StringChunked) -> Result<ListChunked, Error<T::Err>>
+where
+    T: FromStr,
+    Series: NamedFrom<Vec<T>, [T]>,
+{
+    column
+        .iter()
+        .map(|s| {
+            s.map(|s| s.parse::<CS<T>>().map(|cs| cs.into_series("")))
+                .transpose()
+        })
+        .collect::<Result<ListChunked, _>>()
+        .map(|list| list.with_name(column.name().clone()))
+        .map_err(Error::Parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_column, to_list_chunked};
+    use crate::vec::CS;
+    use crate::Error;
+
+    use polars::prelude::*;
+
+    #[test]
+    fn into_series() {
+        let s = CS::new(vec![1u32, 2]).into_series("a");
+        assert_eq!(s.name().as_str(), "a");
+        assert_eq!(s.dtype(), &DataType::UInt32);
+        assert_eq!(s.len(), 2);
+
+        let s = CS::new(vec!["x".to_string()]).into_series("b");
+        assert_eq!(s.dtype(), &DataType::String);
+    }
+
+    #[test]
+    fn list_chunked() {
+        let list = to_list_chunked("ids", vec![CS::new(vec![1i64, 2]), CS::new(vec![3])]);
+        assert_eq!(list.name().as_str(), "ids");
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get_as_series(0).map(|s| s.len()), Some(2));
+    }
+
+    #[test]
+    fn split() {
+        let raw = StringChunked::new("c".into(), &[Some("1,,2"), None, Some("")]);
+        let list = split_column::<i64>(&raw).unwrap();
+        assert_eq!(list.name().as_str(), "c");
+        assert_eq!(list.null_count(), 1);
+        assert_eq!(list.get_as_series(0).map(|s| s.len()), Some(2));
+        assert_eq!(list.get_as_series(2).map(|s| s.len()), Some(0));
+
+        let raw = StringChunked::new("c".into(), &["1,a"]);
+        assert!(matches!(split_column::<i64>(&raw), Err(Error::Parse(_))));
+    }
+}
@@ -0,0 +1,240 @@
+//! Comma separated lists of byte-string identifiers, such as ALPN protocols.
+//!
+//! Bytes outside printable ASCII, as well as `,` and `%`, are written as
+//! `%XX` escapes, so any identifier survives a round trip through a string.
+//! Formats that are not human readable carry the same text as bytes, and
+//! byte input is accepted as raw identifiers that need not be valid UTF-8.
+//!
+//! ```rust
+//! use serde_cs::protocols::ProtocolList;
+//!
+//! let ProtocolList(protos) = serde_json::from_str(r#""h2,http/1.1,x%FF""#).unwrap();
+//! assert_eq!(protos, vec![b"h2".to_vec(), b"http/1.1".to_vec(), b"x\xff".to_vec()]);
+//! ```
+
+use crate::Error;
+
+use serde::de;
+use serde::ser;
+
+use std::convert::Infallible;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ProtocolList(pub Vec<Vec<u8>>);
+
+list_wrapper! {
+    impl[] ProtocolList[](Vec<Vec<u8>>): From, Inner
+}
+
+impl ProtocolList {
+    #[inline]
+    pub fn contains(&self, proto: &[u8]) -> bool {
+        self.0.iter().any(|p| p == proto)
+    }
+
+    /// Parses comma separated bytes, decoding `%XX` escapes in each element.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error<Infallible>> {
+        bytes
+            .split(|&b| b == b',')
+            .filter(|s| !s.is_empty())
+            .map(unescape)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+fn unescape(s: &[u8]) -> Result<Vec<u8>, Error<Infallible>> {
+    fn hex(b: u8) -> Option<u8> {
+        (b as char).to_digit(16).map(|d| d as u8)
+    }
+
+    let mut out = Vec::with_capacity(s.len());
+    let mut it = s.iter();
+    while let Some(&b) = it.next() {
+        if b != b'%' {
+            out.push(b);
+            continue;
+        }
+        let hi = it.next().and_then(|&b| hex(b));
+        let lo = it.next().and_then(|&b| hex(b));
+        match (hi, lo) {
+            (Some(hi), Some(lo)) => out.push(hi << 4 | lo),
+            _ => return Err(Error::InvalidEscape),
+        }
+    }
+    Ok(out)
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, s: &[u8]) -> fmt::Result {
+    for &b in s {
+        if b.is_ascii_graphic() && b != b',' && b != b'%' {
+            write!(f, "{}", b as char)?;
+        } else {
+            write!(f, "%{:02X}", b)?;
+        }
+    }
+    Ok(())
+}
+
+impl FromStr for ProtocolList {
+    type Err = Error<Infallible>;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl IntoIterator for ProtocolList {
+    type Item = Vec<u8>;
+    type IntoIter = vec::IntoIter<Vec<u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl fmt::Display for ProtocolList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            write_escaped(f, v)?;
+        }
+
+        for v in it {
+            f.write_str(",")?;
+            write_escaped(f, v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ser::Serialize for ProtocolList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_bytes(self.to_string().as_bytes())
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for ProtocolList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct ProtocolVisitor;
+
+        impl de::Visitor<'_> for ProtocolVisitor {
+            type Value = ProtocolList;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("comma separated list of protocol identifiers")
+            }
+
+            fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                values.parse().map_err(de::Error::custom)
+            }
+
+            fn visit_bytes<E>(self, values: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ProtocolList::from_bytes(values).map_err(de::Error::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ProtocolVisitor)
+        } else {
+            deserializer.deserialize_bytes(ProtocolVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProtocolList;
+    use crate::Error;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&[u8]>) {
+        let list: Result<ProtocolList, _> = s.parse();
+        assert!(matches!(list, Ok(ProtocolList(v)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("h2,http/1.1", vec![b"h2", b"http/1.1"]);
+        assert_ok_from_str("a%2Cb,%25,%ff%00", vec![b"a,b", b"%", b"\xff\x00"]);
+
+        assert_eq!(
+            "%".parse::<ProtocolList>().unwrap_err(),
+            Error::InvalidEscape
+        );
+        assert_eq!(
+            "%1".parse::<ProtocolList>().unwrap_err(),
+            Error::InvalidEscape
+        );
+        assert_eq!(
+            "%zz".parse::<ProtocolList>().unwrap_err(),
+            Error::InvalidEscape
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        let list = ProtocolList(vec![b"h2".to_vec(), b"a,b c%\xff".to_vec()]);
+        assert_eq!(list.to_string(), "h2,a%2Cb%20c%25%FF");
+        assert_eq!(ProtocolList::default().to_string(), "");
+    }
+
+    #[test]
+    fn round_trip() {
+        let list = ProtocolList(vec![(0..=255).collect(), b"h3".to_vec()]);
+        let parsed: ProtocolList = list.to_string().parse().unwrap();
+        assert_eq!(parsed, list);
+    }
+
+    #[test]
+    fn serde() {
+        let list: Result<ProtocolList, _> = serde_json::from_str(r#""h2,%00""#);
+        assert_eq!(list.unwrap().0, [&b"h2"[..], b"\x00"]);
+
+        let list: Result<ProtocolList, _> = serde_json::from_slice(b"\"h2,\"");
+        assert!(list.unwrap().contains(b"h2"));
+
+        let s = serde_json::to_string(&ProtocolList(vec![b"h2".to_vec(), b"\x01".to_vec()]));
+        assert_eq!(s.unwrap(), r#""h2,%01""#);
+    }
+
+    #[test]
+    fn deserialize_bytes() {
+        use serde::de::value::{BytesDeserializer, Error};
+        use serde::Deserialize;
+
+        let de = BytesDeserializer::<Error>::new(b"h2,\xff%2C");
+        let list = ProtocolList::deserialize(de);
+        assert_eq!(
+            list,
+            Ok(ProtocolList(vec![b"h2".to_vec(), b"\xff,".to_vec()]))
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        let list = ProtocolList::from_bytes(b"h2,\xff\xfe,");
+        assert_eq!(list.unwrap().0, [&b"h2"[..], b"\xff\xfe"]);
+    }
+}
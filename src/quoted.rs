@@ -0,0 +1,220 @@
+//! Lists whose elements are quoted as in RFC 4180 (CSV) when needed.
+//!
+//! Elements containing the separator, a double quote or a line break are
+//! written in double quotes, with inner quotes doubled. A quoted empty
+//! string is kept as an element, and with a
+//! [`Spaced`](crate::separator::Spaced) separator so is an element with
+//! leading whitespace. Segments are found by matching `S::SEP` exactly,
+//! which must not be empty:
+//!
+//! ```rust,compile_fail
+//! struct Empty;
+//!
+//! impl serde_cs::separator::Separator for Empty {
+//!     const SEP: &'static str = "";
+//! }
+//!
+//! let cs = "a".parse::<serde_cs::quoted::CS<String, Empty>>();
+//! ```
+//!
+//! ```rust
+//! use serde_cs::quoted::CS;
+//!
+//! let cs: CS<String> = serde_json::from_str(r#""plain,\"a, b\",\"say \"\"hi\"\"\"""#).unwrap();
+//! assert_eq!(cs.to_inner(), &["plain", "a, b", r#"say "hi""#]);
+//! assert_eq!(cs.to_string(), r#"plain,"a, b","say ""hi""""#);
+//! ```
+
+use crate::separator::{write_sep, Comma, NonEmpty, Separator};
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+pub struct CS<T, S = Comma>(pub Vec<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| {
+            write!(f, "list of CSV-quoted values separated by {:?}", S::SEP)
+        })
+}
+
+fn split<E, S: Separator>(s: &str) -> Result<Vec<String>, Error<E>> {
+    let sep = NonEmpty::<S>::SEP;
+    let mut tokens = Vec::new();
+
+    let mut rest = s;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix(sep) {
+            rest = after;
+            continue;
+        }
+        if S::SPACED && rest.starts_with(char::is_whitespace) {
+            rest = rest.trim_start();
+            continue;
+        }
+
+        let end = rest.find(sep).unwrap_or(rest.len());
+        let Some(quoted) = rest.strip_prefix('"') else {
+            if rest[..end].contains('"') {
+                return Err(Error::StrayQuote);
+            }
+            tokens.push(rest[..end].to_string());
+            rest = &rest[end..];
+            continue;
+        };
+
+        let mut token = String::new();
+        let mut chars = quoted.char_indices();
+        loop {
+            match chars.next() {
+                Some((i, '"')) if quoted[i + 1..].starts_with('"') => {
+                    token.push('"');
+                    chars.next();
+                }
+                Some((i, '"')) => {
+                    rest = &quoted[i + 1..];
+                    break;
+                }
+                Some((_, c)) => token.push(c),
+                None => return Err(Error::UnterminatedQuote),
+            }
+        }
+
+        if !rest.is_empty() && !rest.starts_with(sep) {
+            return Err(Error::StrayQuote);
+        }
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn write_quoted<S: Separator>(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let needs_quotes = s.is_empty()
+        || s.contains(S::SEP)
+        || s.contains(['"', '\r', '\n'])
+        || (S::SPACED && s.starts_with(char::is_whitespace));
+    if !needs_quotes {
+        return f.write_str(s);
+    }
+
+    f.write_str("\"")?;
+    f.write_str(&s.replace('"', "\"\""))?;
+    f.write_str("\"")
+}
+
+impl<T: FromStr, S: Separator> FromStr for CS<T, S> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        split::<_, S>(s)?
+            .iter()
+            .map(|s| S::CASE.apply(s).parse().map_err(Error::Parse))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for CS<T, S> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for CS<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            write_quoted::<S>(f, &S::CASE.apply(&v.to_string()))?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::Semicolon;
+    use crate::Error;
+
+    type CsTest = CS<String>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == expected))
+    }
+
+    fn assert_err_from_str(s: &str, expected: Error<std::convert::Infallible>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Err(e) if e == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("a,,b", vec!["a", "b"]);
+        assert_ok_from_str(r#""a,b",c"#, vec!["a,b", "c"]);
+        assert_ok_from_str(r#""a""b""#, vec![r#"a"b"#]);
+        assert_ok_from_str(r#""",a"#, vec!["", "a"]);
+        assert_ok_from_str("\"line\nbreak\"", vec!["line\nbreak"]);
+
+        assert_err_from_str(r#""a"#, Error::UnterminatedQuote);
+        assert_err_from_str(r#""a"""#, Error::UnterminatedQuote);
+        assert_err_from_str(r#"a"b"#, Error::StrayQuote);
+        assert_err_from_str(r#""a"b,c"#, Error::StrayQuote);
+    }
+
+    #[test]
+    fn to_string() {
+        let cs = CsTest::from(vec!["a".into(), "b,c".into(), r#"d"e"#.into(), "".into()]);
+        assert_eq!(cs.to_string(), r#"a,"b,c","d""e","""#);
+
+        let cs = CS::<_, Semicolon>::from(vec!["a,b", "c;d"]);
+        assert_eq!(cs.to_string(), r#"a,b;"c;d""#);
+    }
+
+    #[test]
+    fn round_trip() {
+        let values = vec![
+            "".to_string(),
+            "a,b".into(),
+            r#""""#.into(),
+            "x\r\ny".into(),
+        ];
+
+        let cs = CsTest::from(values.clone());
+        let s = serde_json::to_string(&cs).unwrap_or_default();
+        let parsed: Result<CsTest, _> = serde_json::from_str(&s);
+        assert_eq!(parsed.unwrap().0, values);
+    }
+
+    #[test]
+    fn separator_policy() {
+        use crate::separator::{Comma, Lower, Spaced, Trailing, Upper};
+
+        let values = vec![" a".to_string(), "b,c".into(), "d".into()];
+        let cs = CS::<_, Spaced<Comma>>::from(values.clone());
+        assert_eq!(cs.to_string(), r#"" a", "b,c", d"#);
+        let parsed: Result<CS<String, Spaced<Comma>>, _> = cs.to_string().parse();
+        assert_eq!(parsed.unwrap().0, values);
+
+        let cs = CS::<_, Trailing<Upper<Comma>>>::from(vec!["a,b", "c"]);
+        assert_eq!(cs.to_string(), r#""A,B",C,"#);
+        let parsed: Result<CS<String, Trailing<Lower<Comma>>>, _> = cs.to_string().parse();
+        assert_eq!(parsed.unwrap().0, ["a,b", "c"]);
+    }
+}
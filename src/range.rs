@@ -0,0 +1,151 @@
+//! Lists of `start..end` ranges, e.g. `"2024-01-01..2024-02-01,2024-03-05..2024-03-10"`.
+//!
+//! Any element type with `FromStr` and `PartialOrd` works; with the `chrono`
+//! feature, [`DateRanges`] covers `chrono::NaiveDate` ranges.
+//!
+//! ```rust
+//! use serde_cs::range::RangeList;
+//!
+//! let RangeList(ranges) = serde_json::from_str::<RangeList<u32>>(r#""1..3,7..9""#).unwrap();
+//! assert_eq!(ranges, vec![1..3, 7..9]);
+//!
+//! let res: Result<RangeList<u32>, _> = serde_json::from_str(r#""3..1""#);
+//! assert!(res.is_err());
+//! ```
+
+use crate::Error;
+
+use std::ops::Range;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeList<T>(pub Vec<Range<T>>);
+
+list_wrapper! {
+    impl[T] RangeList[T](Vec<Range<T>>):
+        Default, AsRef[Range<T>], From, Inner, InnerMut, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of start..end ranges"))
+}
+
+#[cfg(feature = "chrono")]
+pub type DateRanges = RangeList<chrono::NaiveDate>;
+
+impl<T> RangeList<T> {
+    /// Whether any of the ranges contains `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.0.iter().any(|r| r.contains(x))
+    }
+}
+
+fn parse_range<T>(s: &str) -> Result<Range<T>, Error<T::Err>>
+where
+    T: FromStr + PartialOrd,
+{
+    let (start, end) = s.split_once("..").ok_or(Error::MissingDelimiter)?;
+    let (start, end) = (start.parse()?, end.parse()?);
+    if start > end {
+        return Err(Error::InvalidRange);
+    }
+    Ok(start..end)
+}
+
+impl<T: FromStr + PartialOrd> FromStr for RangeList<T> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(parse_range)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<T> IntoIterator for RangeList<T> {
+    type Item = Range<T>;
+    type IntoIter = vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RangeList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(r) = it.next() {
+            write!(f, "{}..{}", r.start, r.end)?;
+        }
+
+        for r in it {
+            write!(f, ",{}..{}", r.start, r.end)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeList;
+    use crate::Error;
+    type RangeTest = RangeList<i32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<std::ops::Range<i32>>) {
+        let ranges: Result<RangeTest, _> = s.parse();
+        assert!(matches!(ranges, Ok(v) if v == RangeList(expected)))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("1..3,5..8", vec![1..3, 5..8]);
+        assert_ok_from_str("-5..-1,,2..2", vec![-5..-1, 2..2]);
+
+        assert_eq!(
+            "3..1".parse::<RangeTest>().unwrap_err(),
+            Error::InvalidRange
+        );
+        assert_eq!(
+            "3".parse::<RangeTest>().unwrap_err(),
+            Error::MissingDelimiter
+        );
+        assert!(matches!("1..a".parse::<RangeTest>(), Err(Error::Parse(_))));
+        assert!(matches!("1...3".parse::<RangeTest>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn contains() {
+        let ranges: RangeTest = "1..3,7..9".parse().unwrap();
+        assert!(ranges.contains(&2));
+        assert!(!ranges.contains(&3));
+        assert!(ranges.contains(&7));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(RangeTest::default().to_string(), "");
+        assert_eq!(RangeList(vec![1..3, -2..0]).to_string(), "1..3,-2..0");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn dates() {
+        use super::DateRanges;
+        use chrono::NaiveDate;
+
+        let s = r#""2024-01-01..2024-02-01,2024-03-05..2024-03-10""#;
+        let ranges: DateRanges = serde_json::from_str(s).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        assert_eq!(ranges.0, [date(1, 1)..date(2, 1), date(3, 5)..date(3, 10)]);
+        assert!(ranges.contains(&date(1, 15)));
+
+        assert_eq!(serde_json::to_string(&ranges).unwrap(), s);
+        assert!("2024-02-01..2024-01-01".parse::<DateRanges>().is_err());
+    }
+}
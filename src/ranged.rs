@@ -0,0 +1,255 @@
+//! Integer lists where `start-end` expands to every value in between, as in
+//! CPU affinity masks or page selections.
+//!
+//! ```rust
+//! use serde_cs::ranged::Ranged;
+//!
+//! let Ranged(cpus) = serde_json::from_str::<Ranged<u32>>(r#""1-5,8,10-12""#).unwrap();
+//! assert_eq!(cpus, vec![1, 2, 3, 4, 5, 8, 10, 11, 12]);
+//! ```
+//!
+//! [`Compressed`] also writes consecutive values back as ranges:
+//!
+//! ```rust
+//! use serde_cs::ranged::Compressed;
+//!
+//! let ids = Compressed::from(vec![1, 2, 3, 5, 6, 9]);
+//! assert_eq!(serde_json::to_string(&ids).unwrap(), r#""1-3,5-6,9""#);
+//! ```
+
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// Integers that can be counted up one at a time.
+pub trait Step: Copy + Ord {
+    /// The next value, or `None` on overflow.
+    fn forward(self) -> Option<Self>;
+
+    /// The number of steps from `self` up to `end`, or `None` if it does not
+    /// fit in a `usize`.
+    fn steps_to(self, end: Self) -> Option<usize>;
+}
+
+macro_rules! impl_step {
+    ($($t:ty),*) => {
+        $(
+            impl Step for $t {
+                #[inline]
+                fn forward(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                #[inline]
+                fn steps_to(self, end: Self) -> Option<usize> {
+                    usize::try_from(end.abs_diff(self)).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The most values a list may expand to. Longer ranges fail with
+/// [`Error::TooMany`] before anything is allocated.
+pub const MAX_LEN: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ranged<T, const COMPRESS: bool = false>(pub Vec<T>);
+
+list_wrapper! {
+    impl[T, const COMPRESS: bool] Ranged[T, COMPRESS](Vec<T>):
+        AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of integers and start-end ranges"))
+}
+
+/// A [`Ranged`] list that writes runs of consecutive values as `start-end`.
+pub type Compressed<T> = Ranged<T, true>;
+
+impl<T, const C: bool> Default for Ranged<T, C> {
+    #[inline]
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+fn expand<T>(s: &str, out: &mut Vec<T>) -> Result<(), Error<T::Err>>
+where
+    T: FromStr + Step,
+{
+    // A leading `-` is the sign of the start, not the range delimiter.
+    let Some((i, _)) = s.char_indices().skip(1).find(|&(_, c)| c == '-') else {
+        out.push(s.parse()?);
+        return Ok(());
+    };
+
+    let (start, end): (T, T) = (s[..i].parse()?, s[i + 1..].parse()?);
+    if start > end {
+        return Err(Error::InvalidRange);
+    }
+
+    let too_many = Error::TooMany { max: MAX_LEN };
+    let n = match start.steps_to(end) {
+        Some(n) if n < MAX_LEN.saturating_sub(out.len()) => n,
+        _ => return Err(too_many),
+    };
+    out.try_reserve(n + 1).map_err(|_| too_many)?;
+
+    let mut cur = start;
+    out.push(cur);
+    while cur < end {
+        cur = cur.forward().ok_or(Error::InvalidRange)?;
+        out.push(cur);
+    }
+    Ok(())
+}
+
+impl<T: FromStr + Step, const C: bool> FromStr for Ranged<T, C> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = Vec::new();
+        for s in s.split(',').filter(|s| !s.is_empty()) {
+            expand(s, &mut values)?;
+        }
+        Ok(Self(values))
+    }
+}
+
+impl<T, const C: bool> IntoIterator for Ranged<T, C> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Ranged<T, false> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display + Step> fmt::Display for Ranged<T, true> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter().copied().peekable();
+        let mut first = true;
+
+        while let Some(start) = it.next() {
+            let mut end = start;
+            while let Some(next) = it.next_if(|&v| end.forward() == Some(v)) {
+                end = next;
+            }
+
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+
+            if start == end {
+                write!(f, "{}", start)?;
+            } else {
+                write!(f, "{}-{}", start, end)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compressed, Ranged, MAX_LEN};
+    use crate::Error;
+    type RangedTest = Ranged<i32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<i32>) {
+        let values: Result<RangedTest, _> = s.parse();
+        assert!(matches!(values, Ok(v) if v == Ranged(expected)))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("1-5,8,10-12", vec![1, 2, 3, 4, 5, 8, 10, 11, 12]);
+        assert_ok_from_str("3-3,,-2", vec![3, -2]);
+        assert_ok_from_str("-3--1,-1-1", vec![-3, -2, -1, -1, 0, 1]);
+
+        assert_eq!(
+            "5-1".parse::<RangedTest>().unwrap_err(),
+            Error::InvalidRange
+        );
+        assert!(matches!("1-".parse::<RangedTest>(), Err(Error::Parse(_))));
+        assert!(matches!(
+            "1-2-3".parse::<RangedTest>(),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!("a".parse::<RangedTest>(), Err(Error::Parse(_))));
+
+        let values: Result<Ranged<u8>, _> = "254-255".parse();
+        assert_eq!(values.unwrap(), Ranged(vec![254, 255]));
+    }
+
+    #[test]
+    fn huge_range() {
+        let too_many = Some(Error::TooMany { max: MAX_LEN });
+        assert_eq!(
+            "0-4294967295".parse::<Ranged<u32>>().err(),
+            too_many.clone()
+        );
+        assert_eq!(
+            "1,0-18446744073709551615".parse::<Ranged<u64>>().err(),
+            too_many.clone()
+        );
+        assert_eq!(
+            "-170141183460469231731687303715884105728-0"
+                .parse::<Ranged<i128>>()
+                .err(),
+            too_many.clone()
+        );
+        assert_eq!("0-1048576".parse::<Ranged<u32>>().err(), too_many.clone());
+        assert_eq!("0,0-1048575".parse::<Ranged<u32>>().err(), too_many.clone());
+
+        let values = "0-1048575".parse::<Ranged<u32>>().map(|v| v.0.len());
+        assert_eq!(values, Ok(MAX_LEN));
+    }
+
+    #[test]
+    fn serde() {
+        let values: Result<Ranged<u16>, _> = serde_json::from_str(r#""8080-8082,9000""#);
+        assert_eq!(values.unwrap().0, [8080, 8081, 8082, 9000]);
+
+        let values: Ranged<u32> = Ranged(vec![1, 2, 3]);
+        let s = serde_json::to_string(&values);
+        assert_eq!(s.unwrap(), r#""1,2,3""#);
+    }
+
+    #[test]
+    fn compressed() {
+        let to_string = |v: Vec<i32>| Compressed::from(v).to_string();
+        assert_eq!(to_string(vec![]), "");
+        assert_eq!(to_string(vec![4]), "4");
+        assert_eq!(to_string(vec![1, 2, 3, 5, 6]), "1-3,5-6");
+        assert_eq!(to_string(vec![-2, -1, 0, 7, 3, 4]), "-2-0,7,3-4");
+        assert_eq!(to_string(vec![1, 1, 2]), "1,1-2");
+        assert_eq!(Compressed::from(vec![254u8, 255]).to_string(), "254-255");
+
+        let ids: Result<Compressed<u32>, _> = serde_json::from_str(r#""10-12,20""#);
+        assert_eq!(ids.as_ref().unwrap().0, [10, 11, 12, 20]);
+        let s = serde_json::to_string(&ids.unwrap());
+        assert_eq!(s.unwrap(), r#""10-12,20""#);
+    }
+}
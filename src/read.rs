@@ -0,0 +1,169 @@
+//! Parsing comma separated lists incrementally from readers.
+//!
+//! Only one element is buffered at a time, so arbitrarily large inputs can be
+//! consumed without reading them into memory first.
+//!
+//! ```rust
+//! use serde_cs::vec::CS;
+//!
+//! let input: &[u8] = b"1,2,,3";
+//! let cs = CS::<u32>::parse_from_reader(input).unwrap();
+//! assert_eq!(cs.0, vec![1, 2, 3]);
+//! ```
+
+use crate::vec::CS;
+
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum ReadError<E> {
+    /// Reading failed.
+    Io(io::Error),
+    /// The input was not valid UTF-8, as with
+    /// [`Error::InvalidUtf8`](crate::Error::InvalidUtf8).
+    InvalidUtf8,
+    /// An element failed to parse.
+    Parse(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => e.fmt(f),
+            Self::InvalidUtf8 => f.write_str("invalid UTF-8"),
+            Self::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::InvalidUtf8 => None,
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl<E> From<io::Error> for ReadError<E> {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+pub(crate) fn parse_segment<T: FromStr>(segment: &[u8]) -> Result<T, ReadError<T::Err>> {
+    let s = std::str::from_utf8(segment).map_err(|_| ReadError::InvalidUtf8)?;
+    s.parse().map_err(ReadError::Parse)
+}
+
+/// Iterator over the elements read from `R`, skipping empty segments.
+pub struct Elements<R, T> {
+    reader: R,
+    buf: Vec<u8>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T> Elements<R, T> {
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn next_segment(&mut self) -> io::Result<Option<&[u8]>> {
+        loop {
+            self.buf.clear();
+            if self.reader.read_until(b',', &mut self.buf)? == 0 {
+                return Ok(None);
+            }
+            if self.buf.last() == Some(&b',') {
+                self.buf.pop();
+            }
+            if !self.buf.is_empty() {
+                break;
+            }
+        }
+
+        Ok(Some(&self.buf))
+    }
+}
+
+impl<R: BufRead, T: FromStr> Iterator for Elements<R, T> {
+    type Item = Result<T, ReadError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_segment() {
+            Ok(Some(segment)) => Some(parse_segment(segment)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
+
+impl<T: FromStr> CS<T> {
+    pub fn parse_from_reader<R: Read>(reader: R) -> Result<Self, ReadError<T::Err>> {
+        Self::parse_from_buf_reader(BufReader::new(reader))
+    }
+
+    pub fn parse_from_buf_reader<R: BufRead>(reader: R) -> Result<Self, ReadError<T::Err>> {
+        Elements::new(reader)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Elements, ReadError};
+    use crate::vec::CS;
+    use std::io::{self, BufReader, Read};
+
+    fn assert_ok_reader(s: &str, expected: Vec<u32>) {
+        let cs = CS::parse_from_reader(s.as_bytes());
+        assert!(matches!(cs, Ok(v) if v == CS::new(expected)))
+    }
+
+    #[test]
+    fn parse_from_reader() {
+        assert_ok_reader("", vec![]);
+        assert_ok_reader(",,,,", vec![]);
+        assert_ok_reader("1", vec![1]);
+        assert_ok_reader(",,,1,,,,,2,,,,,", vec![1, 2]);
+        assert_ok_reader("1,2,3,4,5", vec![1, 2, 3, 4, 5]);
+
+        let cs = CS::<u32>::parse_from_reader("1,a".as_bytes());
+        assert!(matches!(cs, Err(ReadError::Parse(_))));
+        let cs = CS::<String>::parse_from_reader(&b"a,\xff"[..]);
+        assert!(matches!(cs, Err(ReadError::InvalidUtf8)));
+    }
+
+    #[test]
+    fn small_buffer() {
+        let input = "10,200,,3000,4".as_bytes();
+        let reader = BufReader::with_capacity(1, input);
+        let cs = CS::<u32>::parse_from_buf_reader(reader);
+        assert_eq!(cs.unwrap(), CS::new(vec![10, 200, 3000, 4]));
+    }
+
+    #[test]
+    fn elements_are_lazy() {
+        let input = "1,2,a".as_bytes().chain(io::repeat(b'x'));
+        let mut it = Elements::<_, u32>::new(BufReader::new(input));
+        assert_eq!(it.next().map(Result::ok), Some(Some(1)));
+        assert_eq!(it.next().map(Result::ok), Some(Some(2)));
+    }
+}
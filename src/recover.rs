@@ -0,0 +1,139 @@
+//! Parsing that substitutes `T::default()` for invalid elements.
+//!
+//! Positions stay aligned with the input: empty segments are kept too, and
+//! are replaced like any other element that fails to parse. The indices of
+//! replaced elements are kept on the value.
+//!
+//! ```rust
+//! use serde_cs::recover::Recovered;
+//!
+//! let cs: Recovered<u32> = serde_json::from_str(r#""1,x,3,-4""#).unwrap();
+//! assert_eq!(cs.values, vec![1, 0, 3, 0]);
+//! assert_eq!(cs.invalid, vec![1, 3]);
+//!
+//! let cs: Recovered<u32> = "1,,3".parse().unwrap();
+//! assert_eq!(cs.values, vec![1, 0, 3]);
+//! assert_eq!(cs.invalid, vec![1]);
+//! ```
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recovered<T> {
+    pub values: Vec<T>,
+    /// Indices into `values` of the elements that failed to parse.
+    pub invalid: Vec<usize>,
+}
+
+list_wrapper! {
+    impl[T] Recovered[T]:
+        Serialize, Deserialize(|f| f.write_str("comma separated list"))
+}
+
+impl<T> Default for Recovered<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            invalid: Vec::new(),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Recovered<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self {
+            values,
+            invalid: Vec::new(),
+        }
+    }
+}
+
+impl<T> Recovered<T> {
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.values
+    }
+
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.invalid.is_empty()
+    }
+}
+
+impl<T: FromStr + Default> FromStr for Recovered<T> {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cs = Self::default();
+        if s.is_empty() {
+            return Ok(cs);
+        }
+
+        for s in s.split(',') {
+            let value = s.parse().unwrap_or_else(|_| {
+                cs.invalid.push(cs.values.len());
+                T::default()
+            });
+            cs.values.push(value);
+        }
+        Ok(cs)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Recovered<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.values.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Recovered;
+    type RecoverTest = Recovered<u32>;
+
+    fn assert_from_str(s: &str, values: Vec<u32>, invalid: Vec<usize>) {
+        let cs: Result<RecoverTest, _> = s.parse();
+        assert!(matches!(cs, Ok(v) if v == Recovered { values, invalid }))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_from_str("", vec![], vec![]);
+        assert_from_str(",,", vec![0, 0, 0], vec![0, 1, 2]);
+        assert_from_str("1,2", vec![1, 2], vec![]);
+        assert_from_str("a", vec![0], vec![0]);
+        assert_from_str("1,,3", vec![1, 0, 3], vec![1]);
+        assert_from_str("1,2,", vec![1, 2, 0], vec![2]);
+        assert_from_str("1,,a,3,-1", vec![1, 0, 0, 3, 0], vec![1, 2, 4]);
+
+        let cs: Result<Recovered<String>, _> = "a,,b".parse();
+        let v = cs.unwrap();
+        assert_eq!(v.values, ["a", "", "b"]);
+        assert!(v.is_valid());
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<RecoverTest, _> = serde_json::from_str(r#""5,?""#);
+        let v = cs.unwrap();
+        assert_eq!(v.values, [5, 0]);
+        assert!(!v.is_valid());
+        assert!(serde_json::from_str::<RecoverTest>("5").is_err());
+
+        let s = serde_json::to_string(&RecoverTest::from(vec![1, 0]));
+        assert_eq!(s.unwrap(), r#""1,0""#);
+    }
+}
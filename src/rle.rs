@@ -0,0 +1,183 @@
+//! Run-length encoded lists, e.g. `"0*8,1*4"` for eight zeros and four ones.
+//!
+//! Parsing expands every run; formatting compresses consecutive equal
+//! elements back into runs. Wrap the elements in [`vec::CS`](crate::vec::CS)
+//! instead to write them out expanded.
+//!
+//! ```rust
+//! use serde_cs::rle::{RunLength, Times};
+//!
+//! let rle: RunLength<u8> = serde_json::from_str(r#""0*3,1""#).unwrap();
+//! assert_eq!(rle.into_inner(), vec![0, 0, 0, 1]);
+//!
+//! let s = serde_json::to_string(&RunLength::<_, Times>::from(vec!["a", "a", "b"])).unwrap();
+//! assert_eq!(s, r#""a x2,b""#);
+//! ```
+
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// The marker placed between a value and its repeat count.
+pub trait Notation {
+    const MARKER: &'static str;
+}
+
+/// `value*count`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Star;
+
+impl Notation for Star {
+    const MARKER: &'static str = "*";
+}
+
+/// `value xcount`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Times;
+
+impl Notation for Times {
+    const MARKER: &'static str = " x";
+}
+
+/// The most elements a list may expand to. Longer runs fail with
+/// [`Error::TooMany`] before anything is allocated.
+pub const MAX_LEN: usize = 1 << 20;
+
+pub struct RunLength<T, N = Star>(pub Vec<T>, PhantomData<N>);
+
+list_wrapper! {
+    impl[T, N] RunLength[T, N](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of values with repeat counts"))
+}
+
+impl<T, N> RunLength<T, N> {
+    /// Consecutive equal elements with their run lengths.
+    pub fn runs(&self) -> Vec<(&T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut runs: Vec<(&T, usize)> = Vec::new();
+        for v in &self.0 {
+            match runs.last_mut() {
+                Some((last, n)) if *last == v => *n += 1,
+                _ => runs.push((v, 1)),
+            }
+        }
+        runs
+    }
+}
+
+impl<T: FromStr + Clone, N: Notation> FromStr for RunLength<T, N> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut v = Vec::new();
+
+        for s in s.split(',').filter(|s| !s.is_empty()) {
+            match s.rsplit_once(N::MARKER) {
+                Some((value, count)) => {
+                    let count = count.parse().map_err(|_| Error::InvalidCount)?;
+                    if count > MAX_LEN.saturating_sub(v.len()) {
+                        return Err(Error::TooMany { max: MAX_LEN });
+                    }
+                    let value = value.parse()?;
+                    v.try_reserve(count).map_err(|_| Error::InvalidCount)?;
+                    v.extend(std::iter::repeat_n(value, count));
+                }
+                None => v.push(s.parse()?),
+            }
+        }
+        Ok(Self::from(v))
+    }
+}
+
+impl<T, N> IntoIterator for RunLength<T, N> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display + PartialEq, N: Notation> fmt::Display for RunLength<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (v, n)) in self.runs().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if n == 1 {
+                write!(f, "{}", v)?;
+            } else {
+                write!(f, "{}{}{}", v, N::MARKER, n)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RunLength, Times, MAX_LEN};
+    use crate::Error;
+    type RleTest = RunLength<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let rle: Result<RleTest, _> = s.parse();
+        assert!(matches!(rle, Ok(RunLength(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("1,2", vec![1, 2]);
+        assert_ok_from_str("0*3", vec![0, 0, 0]);
+        assert_ok_from_str("0*2,,1*1,2,3*0", vec![0, 0, 1, 2]);
+
+        assert_eq!("1*".parse::<RleTest>().unwrap_err(), Error::InvalidCount);
+        assert_eq!("1*-1".parse::<RleTest>().unwrap_err(), Error::InvalidCount);
+        assert!(matches!("a*2".parse::<RleTest>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn huge_count() {
+        let too_many = Some(Error::TooMany { max: MAX_LEN });
+        assert_eq!("0*18446744073709551615".parse::<RleTest>().err(), too_many);
+        assert_eq!("0*1048577".parse::<RleTest>().err(), too_many);
+        assert_eq!("1,0*1048576".parse::<RleTest>().err(), too_many);
+
+        let rle = "1,0*1048575".parse::<RleTest>().map(|v| v.0.len());
+        assert_eq!(rle, Ok(MAX_LEN));
+    }
+
+    #[test]
+    fn times() {
+        let rle: Result<RunLength<String, Times>, _> = "a x3,b x2,c".parse();
+        assert_eq!(rle.unwrap().0, ["a", "a", "a", "b", "b", "c"]);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(RleTest::default().to_string(), "");
+        assert_eq!(RleTest::from(vec![1, 2]).to_string(), "1,2");
+        assert_eq!(
+            RleTest::from(vec![0, 0, 0, 1, 0, 0]).to_string(),
+            "0*3,1,0*2"
+        );
+        assert_eq!(RunLength::<_, Times>::from(vec![1, 1]).to_string(), "1 x2");
+    }
+
+    #[test]
+    fn serde() {
+        let rle: Result<RleTest, _> = serde_json::from_str(r#""7*2,8""#);
+        assert_eq!(rle.unwrap().0, [7, 7, 8]);
+
+        let s = serde_json::to_string(&RleTest::from(vec![7, 7, 8]));
+        assert_eq!(s.unwrap(), r#""7*2,8""#);
+    }
+}
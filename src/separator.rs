@@ -0,0 +1,428 @@
+//! Delimiters for [`vec::CS`](crate::vec::CS) and [`array::CS`](crate::array::CS).
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use serde_cs::separator::Pipe;
+//! use serde_cs::vec::CS;
+//!
+//! let cs: CS<u32, Pipe> = serde_json::from_str(r#""1|2||3""#).unwrap();
+//! assert_eq!(cs.to_inner(), &[1, 2, 3]);
+//! assert_eq!(cs.to_string(), "1|2|3");
+//! # }
+//! ```
+//!
+//! Other single characters can be picked with [`Char`], or through the
+//! `CSWith` aliases:
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use serde_cs::vec::CSWith;
+//!
+//! let cs: CSWith<u32, '/'> = "1/2".parse().unwrap();
+//! assert_eq!(cs.to_string(), "1/2");
+//! # }
+//! ```
+//!
+//! Separators may be longer than one character; [`separator!`](crate::separator!)
+//! declares a marker for any string:
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! serde_cs::separator!(
+//!     /// ` -> `
+//!     pub Arrow,
+//!     " -> "
+//! );
+//!
+//! let cs: serde_cs::vec::CS<String, Arrow> = "a -> b->c".parse().unwrap();
+//! assert_eq!(cs.into_inner(), vec!["a", "b->c"]);
+//! # }
+//! ```
+
+// Formatting helpers are only used by the list types, which all need `std`
+// or `heapless`.
+#![cfg_attr(
+    not(any(feature = "std", feature = "heapless")),
+    allow(dead_code, unused_imports)
+)]
+
+use core::fmt::{self, Write};
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+/// The delimiter placed between elements.
+pub trait Separator {
+    const SEP: &'static str;
+
+    /// Whether a separator is also written after the last element.
+    const TRAILING: bool = false;
+
+    /// How the case of every segment and formatted element is normalised.
+    const CASE: Case = Case::Preserve;
+
+    /// Whether a space is written after every separator.
+    const SPACED: bool = false;
+
+    /// Splits `s` into segments. Empty segments are skipped by the caller.
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        s.split(Self::SEP)
+    }
+}
+
+/// Letter case applied to segments before parsing and to elements after
+/// formatting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Case {
+    #[default]
+    Preserve,
+    Lower,
+    Upper,
+}
+
+impl Case {
+    #[cfg(feature = "std")]
+    pub(crate) fn apply(self, s: &str) -> Cow<'_, str> {
+        match self {
+            Self::Preserve => Cow::Borrowed(s),
+            Self::Lower => Cow::Owned(s.to_lowercase()),
+            Self::Upper => Cow::Owned(s.to_uppercase()),
+        }
+    }
+
+    pub(crate) fn fmt<T: fmt::Display>(self, v: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Preserve => v.fmt(f),
+            _ => write!(CaseWriter(f, self), "{}", v),
+        }
+    }
+}
+
+/// Changes the case of everything written through it, without allocating.
+struct CaseWriter<'a, 'b>(&'a mut fmt::Formatter<'b>, Case);
+
+impl Write for CaseWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match self.1 {
+                Case::Preserve => self.0.write_char(c)?,
+                Case::Lower => c.to_lowercase().try_for_each(|c| self.0.write_char(c))?,
+                Case::Upper => c.to_uppercase().try_for_each(|c| self.0.write_char(c))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes `S::SEP`, followed by a space for [`Spaced`] separators or when
+/// formatting with `{:#}`, unless the separator already ends in whitespace.
+pub(crate) fn write_sep<S: Separator>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(S::SEP)?;
+    if (S::SPACED || f.alternate()) && !S::SEP.ends_with(char::is_whitespace) {
+        f.write_str(" ")?;
+    }
+    Ok(())
+}
+
+/// `S::SEP`, rejected at compile time if empty, for splitters that advance
+/// past every separator they match.
+#[cfg(feature = "std")]
+pub(crate) struct NonEmpty<S>(PhantomData<S>);
+
+#[cfg(feature = "std")]
+impl<S: Separator> NonEmpty<S> {
+    pub(crate) const SEP: &'static str = {
+        assert!(!S::SEP.is_empty(), "separator must not be empty");
+        S::SEP
+    };
+}
+
+/// Declares a zero-sized [`Separator`](crate::separator::Separator) marker.
+///
+/// The separator must not be empty:
+///
+/// ```rust,compile_fail
+/// serde_cs::separator!(Empty, "");
+/// ```
+#[macro_export]
+macro_rules! separator {
+    ($(#[$doc:meta])* $vis:vis $name:ident, $sep:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name;
+
+        const _: () = assert!(!$sep.is_empty(), "separator must not be empty");
+
+        impl $crate::separator::Separator for $name {
+            const SEP: &'static str = $sep;
+        }
+    };
+}
+
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use regex as __regex;
+
+/// Declares a [`Separator`](crate::separator::Separator) marker splitting on
+/// a regular expression and joining with a fixed string.
+///
+/// The pattern is compiled on first use, and an invalid one panics there.
+///
+/// ```rust
+/// serde_cs::regex_separator!(
+///     /// `,` or `;` with any surrounding whitespace.
+///     pub Loose,
+///     r"\s*[,;]\s*",
+///     ","
+/// );
+///
+/// let cs: serde_cs::vec::CS<u32, Loose> = "1 , 2;3 ;; 4".parse().unwrap();
+/// assert_eq!(cs.to_string(), "1,2,3,4");
+/// ```
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! regex_separator {
+    ($(#[$doc:meta])* $vis:vis $name:ident, $pattern:expr, $sep:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name;
+
+        const _: () = assert!(!$sep.is_empty(), "separator must not be empty");
+
+        impl $crate::separator::Separator for $name {
+            const SEP: &'static str = $sep;
+
+            fn split(s: &str) -> impl Iterator<Item = &str> {
+                use $crate::separator::__regex::Regex;
+
+                static RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+                RE.get_or_init(|| Regex::new($pattern).expect("invalid separator pattern"))
+                    .split(s)
+            }
+        }
+    };
+}
+
+separator!(
+    /// `,`
+    pub Comma,
+    ","
+);
+separator!(
+    /// `;`
+    pub Semicolon,
+    ";"
+);
+separator!(
+    /// `|`
+    pub Pipe,
+    "|"
+);
+separator!(
+    /// ` `
+    pub Space,
+    " "
+);
+separator!(
+    /// `\t`
+    pub Tab,
+    "\t"
+);
+separator!(
+    /// `, `
+    pub CommaSpace,
+    ", "
+);
+separator!(
+    /// `、` (U+3001), the ideographic comma used in Chinese and Japanese.
+    pub IdeographicComma,
+    "\u{3001}"
+);
+separator!(
+    /// `，` (U+FF0C), the fullwidth comma.
+    pub FullwidthComma,
+    "\u{ff0c}"
+);
+separator!(
+    /// `·` (U+00B7)
+    pub MiddleDot,
+    "\u{b7}"
+);
+
+/// Runs of Unicode whitespace when splitting, a single space when joining.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Whitespace;
+
+impl Separator for Whitespace {
+    const SEP: &'static str = " ";
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        s.split_whitespace()
+    }
+}
+
+/// Line feeds, also accepting `\r\n` when splitting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lf;
+
+impl Separator for Lf {
+    const SEP: &'static str = "\n";
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        s.lines()
+    }
+}
+
+/// `\r\n` line endings, also accepting bare `\n` when splitting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CrLf;
+
+impl Separator for CrLf {
+    const SEP: &'static str = "\r\n";
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        s.lines()
+    }
+}
+
+/// `S`, with a separator also written after the last element, as in `"1,2,3,"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Trailing<S>(PhantomData<S>);
+
+impl<S: Separator> Separator for Trailing<S> {
+    const SEP: &'static str = S::SEP;
+    const TRAILING: bool = true;
+    const CASE: Case = S::CASE;
+    const SPACED: bool = S::SPACED;
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        S::split(s)
+    }
+}
+
+/// `S`, with surrounding whitespace trimmed from every segment, so that
+/// `" 1 , 2 ,3 "` parses like `"1,2,3"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Trim<S>(PhantomData<S>);
+
+impl<S: Separator> Separator for Trim<S> {
+    const SEP: &'static str = S::SEP;
+    const TRAILING: bool = S::TRAILING;
+    const CASE: Case = S::CASE;
+    const SPACED: bool = S::SPACED;
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        S::split(s).map(str::trim)
+    }
+}
+
+/// `S`, treating segments that consist only of whitespace as empty, so they
+/// are skipped, become `None`, or are rejected like any other empty segment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlankAsEmpty<S>(PhantomData<S>);
+
+impl<S: Separator> Separator for BlankAsEmpty<S> {
+    const SEP: &'static str = S::SEP;
+    const TRAILING: bool = S::TRAILING;
+    const CASE: Case = S::CASE;
+    const SPACED: bool = S::SPACED;
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        S::split(s).map(|s| if s.trim().is_empty() { "" } else { s })
+    }
+}
+
+/// `S`, writing a space after every separator as in `"a, b, c"`, and
+/// accepting segments with or without leading whitespace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Spaced<S>(PhantomData<S>);
+
+impl<S: Separator> Separator for Spaced<S> {
+    const SEP: &'static str = S::SEP;
+    const TRAILING: bool = S::TRAILING;
+    const CASE: Case = S::CASE;
+    const SPACED: bool = true;
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        S::split(s).map(str::trim_start)
+    }
+}
+
+/// `S`, lowercasing every segment before parsing and every element after
+/// formatting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lower<S>(PhantomData<S>);
+
+impl<S: Separator> Separator for Lower<S> {
+    const SEP: &'static str = S::SEP;
+    const TRAILING: bool = S::TRAILING;
+    const CASE: Case = Case::Lower;
+    const SPACED: bool = S::SPACED;
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        S::split(s)
+    }
+}
+
+/// `S`, uppercasing every segment before parsing and every element after
+/// formatting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Upper<S>(PhantomData<S>);
+
+impl<S: Separator> Separator for Upper<S> {
+    const SEP: &'static str = S::SEP;
+    const TRAILING: bool = S::TRAILING;
+    const CASE: Case = Case::Upper;
+    const SPACED: bool = S::SPACED;
+
+    #[inline]
+    fn split(s: &str) -> impl Iterator<Item = &str> {
+        S::split(s)
+    }
+}
+
+/// Any single `char`, given as a const parameter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Char<const C: char>;
+
+impl<const C: char> Char<C> {
+    const UTF8: [u8; 4] = {
+        let mut buf = [0; 4];
+        C.encode_utf8(&mut buf);
+        buf
+    };
+}
+
+impl<const C: char> Separator for Char<C> {
+    const SEP: &'static str = match core::str::from_utf8(Self::UTF8.split_at(C.len_utf8()).0) {
+        Ok(s) => s,
+        Err(_) => unreachable!(),
+    };
+}
+
+#[cfg(all(test, feature = "regex"))]
+mod tests {
+    use crate::vec::CS;
+
+    crate::regex_separator!(Loose, r"\s*[,;|]\s*", ", ");
+
+    #[test]
+    fn regex_separator() {
+        let cs: Result<CS<u32, Loose>, _> = " 1 ,2|  3 ;4 ".parse();
+        assert!(cs.is_err());
+        let cs: Result<CS<u32, Loose>, _> = "1 ,2|  3 ;4".parse();
+        assert_eq!(cs.unwrap().0, [1, 2, 3, 4]);
+
+        let cs: Result<CS<String, Loose>, _> = serde_json::from_str(r#""a b ;; c""#);
+        assert_eq!(cs.unwrap().0, ["a b", "c"]);
+        assert_eq!(CS::<_, Loose>::from(vec![1, 2]).to_string(), "1, 2");
+    }
+}
@@ -0,0 +1,220 @@
+//! Lists collected into a [`HashSet`] or [`BTreeSet`], dropping duplicates.
+//!
+//! ```rust
+//! use serde_cs::set::CS;
+//!
+//! let scopes: CS<String> = serde_json::from_str(r#""read,write,read""#).unwrap();
+//! assert_eq!(scopes.0.len(), 2);
+//! assert!(scopes.contains("write"));
+//! ```
+//!
+//! [`CS`] formats elements in the hash set's iteration order, which is
+//! unspecified; [`Ordered`] always formats them sorted.
+//!
+//! ```rust
+//! use serde_cs::separator::Space;
+//! use serde_cs::set::Ordered;
+//!
+//! let scopes: Ordered<String, Space> = "profile openid email openid".parse().unwrap();
+//! assert_eq!(scopes.to_string(), "email openid profile");
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+
+use std::borrow::Borrow;
+use std::collections::{btree_set, hash_set, BTreeSet, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct CS<T, S = Comma>(pub HashSet<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](HashSet<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T, S> CS<T, S> {
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Eq + Hash,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.0.contains(value)
+    }
+}
+
+impl<T: FromStr + Eq + Hash, S: Separator> FromStr for CS<T, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        S::split(s)
+            .filter(|s| !s.is_empty())
+            .map(|s| S::CASE.apply(s).parse())
+            .collect::<Result<HashSet<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for CS<T, S> {
+    type Item = T;
+    type IntoIter = hash_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for CS<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A set that formats its elements in ascending order.
+pub struct Ordered<T, S = Comma>(pub BTreeSet<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] Ordered[T, S](BTreeSet<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T, S> Ordered<T, S> {
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.0.contains(value)
+    }
+}
+
+impl<T: FromStr + Ord, S: Separator> FromStr for Ordered<T, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        S::split(s)
+            .filter(|s| !s.is_empty())
+            .map(|s| S::CASE.apply(s).parse())
+            .collect::<Result<BTreeSet<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for Ordered<T, S> {
+    type Item = T;
+    type IntoIter = btree_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for Ordered<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ordered, CS};
+    use crate::separator::{Comma, Lower, Space, Trailing, Upper};
+
+    use std::collections::{BTreeSet, HashSet};
+
+    type CsTest = CS<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let expected: HashSet<_> = expected.into_iter().collect();
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("1,2,,1", vec![1, 2]);
+        assert_ok_from_str("3,3,3", vec![3]);
+        assert!("1,a".parse::<CsTest>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(CsTest::default().to_string(), "");
+        assert_eq!(CsTest::from(HashSet::from([7])).to_string(), "7");
+
+        let s = CsTest::from(HashSet::from([1, 2])).to_string();
+        assert!(s == "1,2" || s == "2,1");
+    }
+
+    #[test]
+    fn separator_policy() {
+        let cs: Result<CS<String, Lower<Comma>>, _> = "A,a,b,".parse();
+        assert_eq!(cs.unwrap().0, HashSet::from(["a".into(), "b".into()]));
+
+        let cs = CS::<_, Trailing<Upper<Comma>>>::from(HashSet::from(["x"]));
+        assert_eq!(cs.to_string(), "X,");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String, Space>, _> = serde_json::from_str(r#""openid email openid""#);
+        assert!(
+            matches!(&cs, Ok(v) if v.contains("openid") && v.contains("email") && v.0.len() == 2)
+        );
+
+        let s = serde_json::to_string(&CS::<_>::from(HashSet::from(["x"])));
+        assert_eq!(s.unwrap(), r#""x""#);
+    }
+
+    #[test]
+    fn ordered() {
+        let set: Result<Ordered<u32>, _> = "3,1,,2,1".parse();
+        assert_eq!(set.as_ref().unwrap().0, BTreeSet::from([1, 2, 3]));
+        assert_eq!(set.unwrap().to_string(), "1,2,3");
+
+        let set: Result<Ordered<String, Space>, _> = serde_json::from_str(r#""write read""#);
+        assert!(set.as_ref().unwrap().contains("read"));
+        let s = serde_json::to_string(&set.unwrap());
+        assert_eq!(s.unwrap(), r#""read write""#);
+        assert_eq!(Ordered::<u32>::default().to_string(), "");
+    }
+
+    #[test]
+    fn ordered_separator_policy() {
+        let set: Result<Ordered<String, Upper<Space>>, _> = "b a B".parse();
+        assert_eq!(set.unwrap().0, BTreeSet::from(["A".into(), "B".into()]));
+
+        let set = Ordered::<_, Trailing<Lower<Comma>>>::from(BTreeSet::from(["Y", "X"]));
+        assert_eq!(set.to_string(), "x,y,");
+    }
+}
@@ -0,0 +1,176 @@
+//! Lists in shared ownership, so that cloning a parsed list only bumps a
+//! reference count.
+//!
+//! [`ArcCS`] can be sent across threads; [`RcCS`] is its single-threaded
+//! counterpart.
+//!
+//! ```rust
+//! use serde_cs::shared::ArcCS;
+//!
+//! let cs: ArcCS<String> = serde_json::from_str(r#""a,b""#).unwrap();
+//! let other = cs.clone();
+//! assert!(std::sync::Arc::ptr_eq(cs.to_inner(), other.to_inner()));
+//!
+//! std::thread::spawn(move || assert_eq!(other.to_string(), "a,b"))
+//!     .join()
+//!     .unwrap();
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::vec;
+
+use serde::de;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
+
+macro_rules! shared_cs {
+    ($(#[$doc:meta])* $name:ident, $ptr:ident) => {
+        $(#[$doc])*
+        pub struct $name<T, S = Comma>(pub $ptr<[T]>, pub PhantomData<S>);
+
+        list_wrapper! {
+            impl[T, S] $name[T, S]($ptr<[T]>, PhantomData):
+                Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, Serialize
+        }
+
+        impl<T, S> From<Vec<T>> for $name<T, S> {
+            #[inline]
+            fn from(v: Vec<T>) -> Self {
+                Self::from($ptr::from(v))
+            }
+        }
+
+        impl<T, S> From<vec::CS<T, S>> for $name<T, S> {
+            #[inline]
+            fn from(cs: vec::CS<T, S>) -> Self {
+                Self::from(cs.into_inner())
+            }
+        }
+
+        impl<T: FromStr, S: Separator> FromStr for $name<T, S> {
+            type Err = T::Err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse::<vec::CS<T, S>>().map(Self::from)
+            }
+        }
+
+        impl<T: fmt::Display, S: Separator> fmt::Display for $name<T, S> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut it = self.0.iter();
+                if let Some(v) = it.next() {
+                    S::CASE.fmt(v, f)?;
+                }
+
+                for v in it {
+                    write_sep::<S>(f)?;
+                    S::CASE.fmt(v, f)?;
+                }
+
+                if S::TRAILING && !self.0.is_empty() {
+                    f.write_str(S::SEP)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl<'de, T, S> de::Deserialize<'de> for $name<T, S>
+        where
+            T: FromStr,
+            T::Err: fmt::Display,
+            S: Separator,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                deserializer
+                    .deserialize_str(SharedVisitor::<vec::CS<T, S>>(PhantomData))
+                    .map(Self::from)
+            }
+        }
+    };
+}
+
+shared_cs!(
+    /// A list behind an [`Arc`].
+    ArcCS,
+    Arc
+);
+shared_cs!(
+    /// A list behind an [`Rc`].
+    RcCS,
+    Rc
+);
+
+struct SharedVisitor<V>(PhantomData<V>);
+
+impl<'de, T, S> de::Visitor<'de> for SharedVisitor<vec::CS<T, S>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    S: Separator,
+{
+    type Value = vec::CS<T, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "list separated by {:?}", S::SEP)
+    }
+
+    fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        values.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArcCS, RcCS};
+    use crate::separator::{Pipe, Trailing};
+    use crate::vec;
+
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[test]
+    fn from_str() {
+        let cs: Result<ArcCS<u32>, _> = ",1,,2".parse();
+        assert_eq!(*cs.unwrap().0, [1, 2]);
+        assert!("1,a".parse::<RcCS<u32>>().is_err());
+    }
+
+    #[test]
+    fn shared() {
+        let cs = RcCS::<u32>::from(vec![1, 2]);
+        let other = cs.clone();
+        assert!(Rc::ptr_eq(cs.to_inner(), other.to_inner()));
+        assert_eq!(Rc::strong_count(&cs.0), 2);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(ArcCS::<u32>::default().to_string(), "");
+        let cs = ArcCS::<_, Trailing<Pipe>>::from(vec::CS::with_separator(vec![1, 2]));
+        assert_eq!(cs.to_string(), "1|2|");
+        assert_eq!(format!("{:#}", RcCS::<_>::from(vec!["a", "b"])), "a, b");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<ArcCS<String, Pipe>, _> = serde_json::from_str(r#""a|b""#);
+        assert_eq!(*cs.as_ref().unwrap().0, ["a", "b"]);
+
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""a|b""#);
+
+        let cs = ArcCS::<u32>::from(Arc::from([3, 4]));
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""3,4""#);
+    }
+}
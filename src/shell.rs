@@ -0,0 +1,216 @@
+//! Comma separated lists whose elements may be quoted shell-style.
+//!
+//! Outside quotes a backslash escapes the next character. Single quotes keep
+//! everything literally, and double quotes only honor `\"` and `\\`. Commas
+//! inside quotes do not split, and a quoted empty string is kept as an element.
+//!
+//! ```rust
+//! use serde_cs::shell::CS;
+//!
+//! let CS(args): CS<String> = serde_json::from_str(r#""-v,'a,b',\"c \\\"d\\\"\"""#).unwrap();
+//! assert_eq!(args, vec!["-v", "a,b", r#"c "d""#]);
+//!
+//! let s = serde_json::to_string(&CS(args)).unwrap();
+//! assert_eq!(s, r#""-v,\"a,b\",\"c \\\"d\\\"\"""#);
+//! ```
+
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CS<T>(pub Vec<T>);
+
+list_wrapper! {
+    impl[T] CS[T](Vec<T>):
+        Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of shell-quoted values"))
+}
+
+fn split<E>(s: &str) -> Result<Vec<String>, Error<E>> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        Bare,
+        Single,
+        Double,
+    }
+
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    let mut state = State::Bare;
+
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (state, c) {
+            (State::Bare, ',') => {
+                if quoted || !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+                quoted = false;
+            }
+            (State::Bare, '\'') => {
+                state = State::Single;
+                quoted = true;
+            }
+            (State::Bare, '"') => {
+                state = State::Double;
+                quoted = true;
+            }
+            (State::Bare, '\\') => token.push(chars.next().ok_or(Error::TrailingEscape)?),
+            (State::Single, '\'') | (State::Double, '"') => state = State::Bare,
+            (State::Double, '\\') => match chars.next() {
+                Some(c @ ('"' | '\\')) => token.push(c),
+                Some(c) => {
+                    token.push('\\');
+                    token.push(c);
+                }
+                None => return Err(Error::UnterminatedQuote),
+            },
+            (_, c) => token.push(c),
+        }
+    }
+
+    if state != State::Bare {
+        return Err(Error::UnterminatedQuote);
+    }
+    if quoted || !token.is_empty() {
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn write_quoted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let needs_quotes = s.is_empty() || s.contains([',', '\'', '"', '\\']);
+    if !needs_quotes {
+        return f.write_str(s);
+    }
+
+    f.write_str("\"")?;
+    for c in s.chars() {
+        if matches!(c, '"' | '\\') {
+            f.write_str("\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    f.write_str("\"")
+}
+
+impl<T: FromStr> FromStr for CS<T> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        split(s)?
+            .iter()
+            .map(|s| T::from_str(s).map_err(Error::Parse))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<T> IntoIterator for CS<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CS<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            write_quoted(f, &v.to_string())?;
+        }
+
+        for v in it {
+            f.write_str(",")?;
+            write_quoted(f, &v.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::Error;
+    type CsTest = CS<String>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v)) if v == expected))
+    }
+
+    fn assert_err_from_str(s: &str, expected: Error<std::convert::Infallible>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Err(e) if e == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,,,", vec![]);
+        assert_ok_from_str("a,,b", vec!["a", "b"]);
+
+        assert_ok_from_str("'a,b',c", vec!["a,b", "c"]);
+        assert_ok_from_str(r#""a,b",c"#, vec!["a,b", "c"]);
+        assert_ok_from_str(r#"'a\b'"#, vec![r#"a\b"#]);
+        assert_ok_from_str(r#""a\"b\\c\d""#, vec![r#"a"b\c\d"#]);
+        assert_ok_from_str(r#"a\,b,c"#, vec!["a,b", "c"]);
+        assert_ok_from_str(r#"pre'fix,'"post""#, vec!["prefix,post"]);
+        assert_ok_from_str(r#"'',"",a"#, vec!["", "", "a"]);
+
+        assert_err_from_str("'a", Error::UnterminatedQuote);
+        assert_err_from_str(r#""a\""#, Error::UnterminatedQuote);
+        assert_err_from_str(r#"a\"#, Error::TrailingEscape);
+    }
+
+    fn assert_to_string(values: Vec<&str>, expected: &str) {
+        let cs = CS(values).to_string();
+        assert_eq!(cs, expected);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_to_string(vec![], "");
+        assert_to_string(vec!["a"], "a");
+        assert_to_string(vec!["a", "b c"], "a,b c");
+        assert_to_string(vec!["a,b", ""], r#""a,b","""#);
+        assert_to_string(vec![r#"it's "x"\"#], r#""it's \"x\"\\""#);
+    }
+
+    #[test]
+    fn round_trip() {
+        let values = vec!["", ",", "'", "\"", "\\", "a b", r#"\",'"#];
+        let s = CS(values.clone()).to_string();
+        assert_ok_from_str(&s, values);
+    }
+
+    fn assert_ok_des(s: &str, expected: Vec<&str>) {
+        let cs: Result<CsTest, _> = serde_json::from_str(s);
+        assert!(matches!(cs, Ok(CS(v)) if v == expected))
+    }
+
+    fn assert_err_des(s: &str) {
+        let cs: Result<CsTest, _> = serde_json::from_str(s);
+        assert!(cs.is_err())
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_ok_des(r#""""#, vec![]);
+        assert_ok_des(r#""a,'b,c'""#, vec!["a", "b,c"]);
+        assert_err_des(r#""'a""#);
+        assert_err_des("1");
+    }
+
+    #[test]
+    fn serialize() {
+        let cs = serde_json::to_string(&CS(vec!["a", "b,c"]));
+        assert_eq!(cs.unwrap(), r#""a,\"b,c\"""#)
+    }
+}
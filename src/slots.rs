@@ -0,0 +1,340 @@
+//! Lists that keep empty segments as `None`, preserving the position of
+//! every slot.
+//!
+//! ```rust
+//! use serde_cs::slots::Slots;
+//!
+//! let slots: Slots<u32> = serde_json::from_str(r#""1,,3""#).unwrap();
+//! assert_eq!(slots.to_inner(), &[Some(1), None, Some(3)]);
+//! assert_eq!(slots.to_string(), "1,,3");
+//! ```
+//!
+//! An empty string is an empty list, so a single `None` is written as `""`
+//! and does not round-trip.
+//!
+//! Another [`NoneToken`] marks missing values with a placeholder instead,
+//! and empty segments then parse like any other:
+//!
+//! ```rust
+//! use serde_cs::separator::Comma;
+//! use serde_cs::slots::{Dash, Slots};
+//!
+//! let slots: Slots<String, Comma, Dash> = "a,-,".parse().unwrap();
+//! assert_eq!(slots.to_inner(), &[Some("a".into()), None, Some("".into())]);
+//! assert_eq!(slots.to_string(), "a,-,");
+//! ```
+//!
+//! [`FixedSlots`] has a fixed number of positions, so `"0"` and a missing
+//! value stay distinguishable in every column:
+//!
+//! ```rust
+//! use serde_cs::slots::FixedSlots;
+//!
+//! let row: FixedSlots<u32, 4> = "0,,7".parse().unwrap();
+//! assert_eq!(row.0, [Some(0), None, Some(7), None]);
+//! assert_eq!(row.to_string(), "0,,7,");
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{array, fmt, vec};
+
+/// The segment that stands for `None`.
+pub trait NoneToken {
+    const TOKEN: &'static str;
+}
+
+macro_rules! none_token {
+    ($(#[$doc:meta])* $name:ident, $token:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl NoneToken for $name {
+            const TOKEN: &'static str = $token;
+        }
+    };
+}
+
+none_token!(
+    /// An empty segment.
+    Blank,
+    ""
+);
+none_token!(
+    /// `-`
+    Dash,
+    "-"
+);
+none_token!(
+    /// `null`
+    Null,
+    "null"
+);
+
+/// Splits `s`, dropping the empty segment after a trailing separator.
+fn segments<S: Separator>(s: &str) -> Vec<&str> {
+    let mut segments: Vec<_> = S::split(s).collect();
+    if S::TRAILING && segments.len() > 1 && segments.last() == Some(&"") {
+        segments.pop();
+    }
+    segments
+}
+
+pub struct Slots<T, S = Comma, N = Blank>(pub Vec<Option<T>>, pub PhantomData<(S, N)>);
+
+list_wrapper! {
+    impl[T, S, N] Slots[T, S, N](Vec<Option<T>>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[Option<T>], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T: FromStr, S: Separator, N: NoneToken> FromStr for Slots<T, S, N> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        segments::<S>(s)
+            .into_iter()
+            .map(|s| {
+                (s != N::TOKEN)
+                    .then(|| S::CASE.apply(s).parse())
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S, N> IntoIterator for Slots<T, S, N> {
+    type Item = Option<T>;
+    type IntoIter = vec::IntoIter<Option<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator, N: NoneToken> fmt::Display for Slots<T, S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            match v {
+                Some(v) => S::CASE.fmt(v, f)?,
+                None => f.write_str(N::TOKEN)?,
+            }
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Exactly `N` positional slots, where missing trailing slots are `None` as
+/// well. More than `N` segments fail with [`Error::TooMany`].
+pub struct FixedSlots<T, const N: usize, S = Comma, Nt = Blank>(
+    pub [Option<T>; N],
+    pub PhantomData<(S, Nt)>,
+);
+
+list_wrapper! {
+    impl[T, const N: usize, S, Nt] FixedSlots[T, N, S, Nt]([Option<T>; N], PhantomData):
+        Debug, Clone, PartialEq, Eq, AsRef[Option<T>], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| {
+            write!(f, "list of at most {} slots separated by {:?}", N, S::SEP)
+        })
+}
+
+impl<T, const N: usize, S, Nt> Default for FixedSlots<T, N, S, Nt> {
+    #[inline]
+    fn default() -> Self {
+        Self::from(array::from_fn(|_| None))
+    }
+}
+
+impl<T: FromStr, const N: usize, S: Separator, Nt: NoneToken> FromStr for FixedSlots<T, N, S, Nt> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut slots = Self::default();
+        if s.is_empty() {
+            return Ok(slots);
+        }
+
+        for (i, s) in segments::<S>(s).into_iter().enumerate() {
+            let slot = slots.0.get_mut(i).ok_or(Error::TooMany { max: N })?;
+            if s != Nt::TOKEN {
+                *slot = Some(S::CASE.apply(s).parse()?);
+            }
+        }
+        Ok(slots)
+    }
+}
+
+impl<T, const N: usize, S, Nt> IntoIterator for FixedSlots<T, N, S, Nt> {
+    type Item = Option<T>;
+    type IntoIter = array::IntoIter<Option<T>, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator, Nt: NoneToken> fmt::Display
+    for FixedSlots<T, N, S, Nt>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write_sep::<S>(f)?;
+            }
+            match v {
+                Some(v) => S::CASE.fmt(v, f)?,
+                None => f.write_str(Nt::TOKEN)?,
+            }
+        }
+
+        if S::TRAILING && N > 0 {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slots;
+    use crate::separator::Semicolon;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<Option<u32>>) {
+        let slots: Result<Slots<u32>, _> = s.parse();
+        assert!(matches!(slots, Ok(Slots(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",", vec![None, None]);
+        assert_ok_from_str("1", vec![Some(1)]);
+        assert_ok_from_str("1,,3", vec![Some(1), None, Some(3)]);
+        assert_ok_from_str(",2,", vec![None, Some(2), None]);
+
+        assert!("1,a".parse::<Slots<u32>>().is_err());
+        assert!("1, ".parse::<Slots<u32>>().is_err());
+    }
+
+    #[test]
+    fn blank_segments() {
+        use crate::separator::{BlankAsEmpty, Comma};
+
+        let slots: Result<Slots<u32, BlankAsEmpty<Comma>>, _> = "1, ,3".parse();
+        assert_eq!(slots.unwrap().0, [Some(1), None, Some(3)]);
+    }
+
+    #[test]
+    fn trailing() {
+        use crate::separator::{Comma, Trailing};
+
+        type SlotsTrailing = Slots<u32, Trailing<Comma>>;
+
+        let slots: Result<SlotsTrailing, _> = "1,2,".parse();
+        assert_eq!(slots.unwrap().0, [Some(1), Some(2)]);
+        let slots: Result<SlotsTrailing, _> = "1,,".parse();
+        assert_eq!(slots.unwrap().0, [Some(1), None]);
+
+        let slots = SlotsTrailing::from(vec![Some(1), None]);
+        assert_eq!(slots.to_string(), "1,,");
+        assert_eq!(slots.to_string().parse::<SlotsTrailing>().unwrap(), slots);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Slots::<u32>::default().to_string(), "");
+        assert_eq!(
+            Slots::<_>::from(vec![Some(1), None, Some(3)]).to_string(),
+            "1,,3"
+        );
+        assert_eq!(Slots::<u32>::from(vec![None, None]).to_string(), ",");
+        assert_eq!(
+            Slots::<_, Semicolon>::from(vec![None, Some(2)]).to_string(),
+            ";2"
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let slots: Result<Slots<String, Semicolon>, _> = serde_json::from_str(r#""a;;b""#);
+        assert!(
+            matches!(slots, Ok(Slots(v, _)) if v == [Some("a".into()), None, Some("b".into())])
+        );
+
+        let s = serde_json::to_string(&Slots::<_>::from(vec![None, Some(2)]));
+        assert_eq!(s.unwrap(), r#"",2""#);
+    }
+
+    #[test]
+    fn none_token() {
+        use super::{Dash, Null};
+        use crate::separator::Comma;
+
+        let slots: Result<Slots<u32, Comma, Null>, _> = "1,null,3".parse();
+        assert_eq!(slots.as_ref().unwrap().0, [Some(1), None, Some(3)]);
+        assert_eq!(slots.map(|s| s.to_string()), Ok("1,null,3".to_string()));
+
+        assert!("1,,3".parse::<Slots<u32, Comma, Dash>>().is_err());
+        let slots: Result<Slots<u32, Comma, Dash>, _> = serde_json::from_str(r#""-""#);
+        assert_eq!(slots.unwrap().0, [None]);
+        let s = serde_json::to_string(&Slots::<u32, Comma, Dash>::from(vec![None]));
+        assert_eq!(s.unwrap(), r#""-""#);
+    }
+
+    #[test]
+    fn fixed_slots() {
+        use super::{FixedSlots, Null};
+        use crate::separator::Comma;
+        use crate::Error;
+
+        let slots: Result<FixedSlots<u32, 3>, _> = "".parse();
+        assert_eq!(slots.unwrap().0, [None, None, None]);
+        let slots: Result<FixedSlots<u32, 3>, _> = ",2".parse();
+        assert_eq!(slots.unwrap().0, [None, Some(2), None]);
+        let slots: Result<FixedSlots<u32, 2>, _> = "1,2,3".parse();
+        assert_eq!(slots.unwrap_err(), Error::TooMany { max: 2 });
+        assert!("1,x".parse::<FixedSlots<u32, 2>>().is_err());
+
+        let slots = FixedSlots::<String, 3, Comma, Null>::from([None, Some("".into()), None]);
+        assert_eq!(slots.to_string(), "null,,null");
+        let s = serde_json::to_string(&slots);
+        assert_eq!(s.unwrap(), r#""null,,null""#);
+
+        let back: Result<FixedSlots<String, 3, Comma, Null>, _> =
+            serde_json::from_str(r#""null,,null""#);
+        assert_eq!(back.unwrap(), slots);
+    }
+
+    #[test]
+    fn fixed_slots_trailing() {
+        use super::FixedSlots;
+        use crate::separator::{Comma, Trailing};
+
+        type Row = FixedSlots<u32, 3, Trailing<Comma>>;
+
+        let row: Result<Row, _> = "1,2,".parse();
+        assert_eq!(row.unwrap().0, [Some(1), Some(2), None]);
+        let row: Result<Row, _> = "1,2,3,".parse();
+        assert_eq!(row.as_ref().unwrap().0, [Some(1), Some(2), Some(3)]);
+        assert_eq!(row.unwrap().to_string(), "1,2,3,");
+    }
+}
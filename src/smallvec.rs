@@ -0,0 +1,113 @@
+//! Lists stored inline in a [`SmallVec`], so that up to `N` elements are
+//! parsed without allocating.
+//!
+//! Requires the `smallvec` feature.
+//!
+//! ```rust
+//! use serde_cs::smallvec::CS;
+//!
+//! let cs: CS<u32, 4> = serde_json::from_str(r#""1,2,3""#).unwrap();
+//! assert!(!cs.0.spilled());
+//! assert_eq!(cs.to_string(), "1,2,3");
+//! ```
+//!
+//! Longer lists still parse, spilling onto the heap.
+
+use crate::separator::{write_sep, Comma, Separator};
+
+use smallvec::SmallVec;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct CS<T, const N: usize, S = Comma>(pub SmallVec<[T; N]>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, const N: usize, S] CS[T, N, S](SmallVec<[T; N]>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "list separated by {:?}", S::SEP))
+}
+
+impl<T: FromStr, const N: usize, S: Separator> FromStr for CS<T, N, S> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        S::split(s)
+            .filter(|s| !s.is_empty())
+            .map(|s| S::CASE.apply(s).parse())
+            .collect::<Result<SmallVec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, const N: usize, S> IntoIterator for CS<T, N, S> {
+    type Item = T;
+    type IntoIter = smallvec::IntoIter<[T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const N: usize, S: Separator> fmt::Display for CS<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::{Pipe, Trailing};
+
+    use smallvec::SmallVec;
+
+    type CsTest = CS<u32, 2>;
+
+    fn assert_ok_from_str(s: &str, expected: &[u32], spilled: bool) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v.as_slice() == expected && v.spilled() == spilled))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", &[], false);
+        assert_ok_from_str(",1,,2,", &[1, 2], false);
+        assert_ok_from_str("1,2,3", &[1, 2, 3], true);
+        assert!("1,a".parse::<CsTest>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(CsTest::default().to_string(), "");
+        let cs = CsTest::from(SmallVec::from_slice(&[1, 2, 3]));
+        assert_eq!(format!("{:#}", cs), "1, 2, 3");
+
+        let cs = CS::<_, 2, Trailing<Pipe>>::from(SmallVec::from_slice(&[1]));
+        assert_eq!(cs.to_string(), "1|");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String, 4, Pipe>, _> = serde_json::from_str(r#""a|b""#);
+        assert_eq!(cs.unwrap().0.as_slice(), ["a", "b"]);
+
+        let s = serde_json::to_string(&CsTest::from(SmallVec::from_slice(&[1, 2])));
+        assert_eq!(s.unwrap(), r#""1,2""#);
+    }
+}
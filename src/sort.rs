@@ -0,0 +1,147 @@
+//! REST-style sort keys, where a `-` prefix requests descending order.
+//!
+//! ```rust
+//! use serde_cs::sort::{SortDirection, SortKeys};
+//!
+//! let SortKeys(keys) = serde_json::from_str(r#""-created_at,name""#).unwrap();
+//! assert_eq!(
+//!     keys,
+//!     vec![
+//!         ("created_at".to_string(), SortDirection::Desc),
+//!         ("name".to_string(), SortDirection::Asc),
+//!     ]
+//! );
+//! ```
+
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Sort keys in priority order. A leading `+` is accepted for ascending keys,
+/// and only written back for ascending keys that start with `-` or `+`
+/// themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKeys<K = String>(pub Vec<(K, SortDirection)>);
+
+list_wrapper! {
+    impl[K] SortKeys[K](Vec<(K, SortDirection)>):
+        Default, From, Inner, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of sort keys"))
+}
+
+fn parse_key<K: FromStr>(s: &str) -> Result<(K, SortDirection), Error<K::Err>> {
+    let (key, dir) = match s.strip_prefix('-') {
+        Some(key) => (key, SortDirection::Desc),
+        None => (s.strip_prefix('+').unwrap_or(s), SortDirection::Asc),
+    };
+
+    if key.is_empty() {
+        return Err(Error::Empty);
+    }
+    Ok((key.parse()?, dir))
+}
+
+impl<K: FromStr> FromStr for SortKeys<K> {
+    type Err = Error<K::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(parse_key)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<K> IntoIterator for SortKeys<K> {
+    type Item = (K, SortDirection);
+    type IntoIter = vec::IntoIter<(K, SortDirection)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for SortKeys<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, dir)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            let key = key.to_string();
+            if *dir == SortDirection::Desc {
+                f.write_str("-")?;
+            } else if key.starts_with(['-', '+']) {
+                f.write_str("+")?;
+            }
+            f.write_str(&key)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortDirection::{Asc, Desc};
+    use super::{SortDirection, SortKeys};
+    use crate::Error;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<(&str, SortDirection)>) {
+        let keys: Result<SortKeys, _> = s.parse();
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(k, d)| (k.to_string(), d))
+            .collect();
+        assert!(matches!(keys, Ok(SortKeys(v)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(",,", vec![]);
+        assert_ok_from_str("name", vec![("name", Asc)]);
+        assert_ok_from_str("+name", vec![("name", Asc)]);
+        assert_ok_from_str(
+            "-created_at,,name",
+            vec![("created_at", Desc), ("name", Asc)],
+        );
+        assert_ok_from_str("--a", vec![("-a", Desc)]);
+
+        assert_eq!("-".parse::<SortKeys>().unwrap_err(), Error::Empty);
+        assert_eq!("a,+".parse::<SortKeys>().unwrap_err(), Error::Empty);
+        assert!("-a".parse::<SortKeys<u32>>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(SortKeys::<String>::default().to_string(), "");
+        let keys = SortKeys(vec![("a", Desc), ("b", Asc)]);
+        assert_eq!(keys.to_string(), "-a,b");
+
+        let keys = SortKeys(vec![
+            ("-a".into(), Asc),
+            ("+b".into(), Asc),
+            ("-c".into(), Desc),
+        ]);
+        assert_eq!(keys.to_string(), "+-a,++b,--c");
+        assert_eq!(keys.to_string().parse::<SortKeys>().unwrap(), keys);
+    }
+
+    #[test]
+    fn serde() {
+        let keys: Result<SortKeys<u32>, _> = serde_json::from_str(r#""-1,+2""#);
+        assert_eq!(keys.unwrap().0, [(1, Desc), (2, Asc)]);
+
+        let s = serde_json::to_string(&SortKeys(vec![(1, Asc), (2, Desc)]));
+        assert_eq!(s.unwrap(), r#""1,-2""#);
+    }
+}
@@ -0,0 +1,138 @@
+//! Sparse arrays written as `index:value` pairs, e.g. `"0:5,3:9,7:1"`.
+//!
+//! ```rust
+//! use serde_cs::sparse::Sparse;
+//!
+//! let Sparse(readings) = serde_json::from_str::<Sparse<u32, 4>>(r#""0:5,3:9""#).unwrap();
+//! assert_eq!(readings, vec![Some(5), None, None, Some(9)]);
+//! ```
+
+use crate::Error;
+
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// A list of `LEN` optional slots; every index must be below `LEN` and may
+/// appear only once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sparse<T, const LEN: usize>(pub Vec<Option<T>>);
+
+list_wrapper! {
+    impl[T, const LEN: usize] Sparse[T, LEN](Vec<Option<T>>):
+        AsRef[Option<T>], Inner, InnerMut, Serialize,
+        Deserialize(|f| f.write_str("comma separated list of index:value pairs"))
+}
+
+impl<T, const LEN: usize> Default for Sparse<T, LEN> {
+    #[inline]
+    fn default() -> Self {
+        Self(std::iter::repeat_with(|| None).take(LEN).collect())
+    }
+}
+
+impl<T, const LEN: usize> Sparse<T, LEN> {
+    /// Iterates over the filled slots with their indices.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.as_ref().map(|v| (i, v)))
+    }
+}
+
+impl<T: FromStr, const LEN: usize> FromStr for Sparse<T, LEN> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sparse = Self::default();
+
+        for s in s.split(',').filter(|s| !s.is_empty()) {
+            let (index, value) = s.split_once(':').ok_or(Error::MissingDelimiter)?;
+            let slot = index
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| sparse.0.get_mut(i))
+                .ok_or(Error::InvalidIndex)?;
+
+            if slot.is_some() {
+                return Err(Error::Duplicate);
+            }
+            *slot = Some(value.parse()?);
+        }
+        Ok(sparse)
+    }
+}
+
+impl<T, const LEN: usize> IntoIterator for Sparse<T, LEN> {
+    type Item = Option<T>;
+    type IntoIter = vec::IntoIter<Option<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const LEN: usize> fmt::Display for Sparse<T, LEN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.iter();
+        if let Some((i, v)) = it.next() {
+            write!(f, "{}:{}", i, v)?;
+        }
+
+        for (i, v) in it {
+            write!(f, ",{}:{}", i, v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sparse;
+    use crate::Error;
+    type SparseTest = Sparse<u32, 4>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<Option<u32>>) {
+        let sparse: Result<SparseTest, _> = s.parse();
+        assert!(matches!(sparse, Ok(Sparse(v)) if v == expected))
+    }
+
+    fn assert_err_from_str(s: &str, expected: Error<std::num::ParseIntError>) {
+        let sparse: Result<SparseTest, _> = s.parse();
+        assert!(matches!(sparse, Err(e) if e == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![None; 4]);
+        assert_ok_from_str(",,", vec![None; 4]);
+        assert_ok_from_str("0:5,3:9", vec![Some(5), None, None, Some(9)]);
+        assert_ok_from_str("2:1,,0:0", vec![Some(0), None, Some(1), None]);
+
+        assert_err_from_str("4:1", Error::InvalidIndex);
+        assert_err_from_str("-1:1", Error::InvalidIndex);
+        assert_err_from_str("x:1", Error::InvalidIndex);
+        assert_err_from_str("1:1,1:2", Error::Duplicate);
+        assert_err_from_str("1", Error::MissingDelimiter);
+        assert!(matches!("1:a".parse::<SparseTest>(), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(SparseTest::default().to_string(), "");
+        assert_eq!(
+            Sparse::<u32, 4>(vec![Some(5), None, None, Some(9)]).to_string(),
+            "0:5,3:9"
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let sparse: Result<Sparse<u32, 2>, _> = serde_json::from_str(r#""1:7""#);
+        assert_eq!(sparse.unwrap().0, [None, Some(7)]);
+
+        let s = serde_json::to_string(&Sparse::<u32, 3>(vec![None, Some(1), Some(2)]));
+        assert_eq!(s.unwrap(), r#""1:1,2:2""#);
+    }
+}
@@ -0,0 +1,254 @@
+//! Asynchronous parsing of comma separated lists arriving in chunks.
+//!
+//! Elements are yielded as soon as they are complete, and elements split
+//! across chunk boundaries are reassembled. Enabled by the `futures` feature;
+//! reading from `tokio` readers additionally requires the `tokio` feature.
+
+use crate::parser::Splitter;
+use crate::read::{parse_segment, ReadError};
+
+use futures_core::Stream;
+
+use std::io;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+#[derive(Debug)]
+struct Chunks<T> {
+    splitter: Splitter,
+    done: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromStr> Chunks<T> {
+    fn new() -> Self {
+        Self {
+            splitter: Splitter::default(),
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Polls for the next element, pulling more input from `fill` as needed.
+    /// `fill` returns `Ready(Ok(false))` once the input is exhausted.
+    fn poll_next_with(
+        &mut self,
+        mut fill: impl FnMut(&mut Splitter) -> Poll<io::Result<bool>>,
+    ) -> Poll<Option<Result<T, ReadError<T::Err>>>> {
+        loop {
+            if let Some(segment) = self.splitter.next_segment() {
+                return Poll::Ready(Some(parse_segment(segment)));
+            }
+            if self.done {
+                return Poll::Ready(self.splitter.finish().map(parse_segment));
+            }
+
+            match fill(&mut self.splitter) {
+                Poll::Ready(Ok(more)) => self.done = !more,
+                Poll::Ready(Err(e)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(e.into())));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Stream of the elements parsed from a stream of byte chunks, such as `Bytes`.
+#[derive(Debug)]
+pub struct Elements<S, T> {
+    inner: S,
+    chunks: Chunks<T>,
+}
+
+impl<S, T: FromStr> Elements<S, T> {
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            chunks: Chunks::new(),
+        }
+    }
+}
+
+impl<S, T> Stream for Elements<S, T>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[u8]>,
+    T: FromStr,
+{
+    type Item = Result<T, ReadError<T::Err>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let inner = &mut this.inner;
+        this.chunks.poll_next_with(|splitter| {
+            Pin::new(&mut *inner)
+                .poll_next(cx)
+                .map(|chunk| match chunk {
+                    Some(chunk) => {
+                        splitter.push(chunk.as_ref());
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                })
+        })
+    }
+}
+
+/// Stream of the elements parsed from a `tokio` reader.
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct ReaderElements<R, T> {
+    reader: R,
+    chunks: Chunks<T>,
+    buf: Box<[u8]>,
+}
+
+#[cfg(feature = "tokio")]
+impl<R, T: FromStr> ReaderElements<R, T> {
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(8 * 1024, reader)
+    }
+
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        Self {
+            reader,
+            chunks: Chunks::new(),
+            buf: vec![0; capacity.max(1)].into_boxed_slice(),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R, T> Stream for ReaderElements<R, T>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    T: FromStr,
+{
+    type Item = Result<T, ReadError<T::Err>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let (reader, buf) = (&mut this.reader, &mut this.buf);
+        this.chunks.poll_next_with(|splitter| {
+            let mut read_buf = tokio::io::ReadBuf::new(buf);
+            Pin::new(&mut *reader)
+                .poll_read(cx, &mut read_buf)
+                .map_ok(|()| {
+                    splitter.push(read_buf.filled());
+                    !read_buf.filled().is_empty()
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Elements;
+    use crate::read::ReadError;
+
+    use futures_core::Stream;
+
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    /// Yields its chunks one by one, returning `Pending` before each of them.
+    struct Chunked {
+        chunks: Vec<&'static str>,
+        ready: bool,
+    }
+
+    impl Stream for Chunked {
+        type Item = &'static str;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            if self.chunks.is_empty() {
+                Poll::Ready(None)
+            } else {
+                Poll::Ready(Some(self.chunks.remove(0)))
+            }
+        }
+    }
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut items = Vec::new();
+        loop {
+            match Pin::new(&mut stream).poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => items.push(item),
+                Poll::Ready(None) => return items,
+                Poll::Pending => {}
+            }
+        }
+    }
+
+    fn assert_ok_chunks(chunks: Vec<&'static str>, expected: Vec<u32>) {
+        let stream = Elements::<_, u32>::new(Chunked {
+            chunks,
+            ready: false,
+        });
+        let items = collect(stream);
+        assert!(items.iter().all(Result::is_ok));
+        assert_eq!(items.into_iter().flatten().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn elements() {
+        assert_ok_chunks(vec![], vec![]);
+        assert_ok_chunks(vec!["", ",,", ""], vec![]);
+        assert_ok_chunks(vec!["1,2,3"], vec![1, 2, 3]);
+        assert_ok_chunks(vec!["1", "2,", ",3", "4"], vec![12, 34]);
+        assert_ok_chunks(vec!["1", ",", "2", ",", ""], vec![1, 2]);
+    }
+
+    #[test]
+    fn elements_err() {
+        let stream = Elements::<_, u32>::new(Chunked {
+            chunks: vec!["1,a", ",2"],
+            ready: true,
+        });
+        let items = collect(stream);
+        assert!(matches!(
+            items[..],
+            [Ok(1), Err(ReadError::Parse(_)), Ok(2)]
+        ));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn reader_elements() {
+        use super::ReaderElements;
+
+        let reader = "10,,200,3000".as_bytes();
+        let items = collect(ReaderElements::<_, u32>::with_capacity(2, reader));
+        assert_eq!(
+            items.into_iter().flatten().collect::<Vec<_>>(),
+            [10, 200, 3000]
+        );
+
+        let reader = &b"a,\xff,b"[..];
+        let items = collect(ReaderElements::<_, String>::with_capacity(2, reader));
+        assert!(matches!(
+            items[..],
+            [Ok(_), Err(ReadError::InvalidUtf8), Ok(_)]
+        ));
+    }
+}
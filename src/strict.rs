@@ -0,0 +1,151 @@
+//! Lists that reject empty segments instead of skipping them.
+//!
+//! ```rust
+//! use serde_cs::strict::CS;
+//! use serde_cs::Error;
+//!
+//! let cs: CS<u32> = serde_json::from_str(r#""1,2""#).unwrap();
+//! assert_eq!(cs.to_inner(), &[1, 2]);
+//!
+//! assert_eq!("1,,2".parse::<CS<u32>>(), Err(Error::Empty));
+//! assert_eq!(",1".parse::<CS<u32>>(), Err(Error::Empty));
+//! ```
+//!
+//! An empty string is still an empty list.
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+pub struct CS<T, S = Comma>(pub Vec<T>, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](Vec<T>, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, AsRef[T], From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| {
+            write!(f, "list separated by {:?} without empty elements", S::SEP)
+        })
+}
+
+impl<T: FromStr, S: Separator> FromStr for CS<T, S> {
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut segments: Vec<_> = S::split(s).collect();
+        if S::TRAILING && segments.len() > 1 && segments.last() == Some(&"") {
+            segments.pop();
+        }
+
+        segments
+            .into_iter()
+            .map(|s| match s {
+                "" => Err(Error::Empty),
+                s => S::CASE.apply(s).parse().map_err(Error::Parse),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl<T, S> IntoIterator for CS<T, S> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for CS<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CS;
+    use crate::separator::Pipe;
+    use crate::Error;
+
+    type CsTest = CS<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == expected))
+    }
+
+    fn assert_err_from_str(s: &str) {
+        let cs: Result<CsTest, _> = s.parse();
+        assert!(matches!(cs, Err(Error::Empty)))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str("1", vec![1]);
+        assert_ok_from_str("1,2,3", vec![1, 2, 3]);
+
+        assert_err_from_str(",");
+        assert_err_from_str(",1");
+        assert_err_from_str("1,");
+        assert_err_from_str("1,,2");
+
+        assert!(matches!("1,a".parse::<CsTest>(), Err(Error::Parse(_))));
+        assert_eq!("1|2".parse::<CS<u32, Pipe>>().unwrap().0, [1, 2]);
+    }
+
+    #[test]
+    fn blank_segments() {
+        use crate::separator::{BlankAsEmpty, Comma};
+
+        assert!(matches!("1, ,2".parse::<CsTest>(), Err(Error::Parse(_))));
+        let cs: Result<CS<String, BlankAsEmpty<Comma>>, _> = "a, ,b".parse();
+        assert_eq!(cs.unwrap_err(), Error::Empty);
+    }
+
+    #[test]
+    fn trailing() {
+        use crate::separator::{Comma, Trailing};
+
+        type CsTrailing = CS<u32, Trailing<Comma>>;
+
+        assert_eq!("1,2,".parse::<CsTrailing>().unwrap().0, [1, 2]);
+        assert_eq!("1,2".parse::<CsTrailing>().unwrap().0, [1, 2]);
+        assert_eq!("1,,".parse::<CsTrailing>().unwrap_err(), Error::Empty);
+        assert_eq!(",".parse::<CsTrailing>().unwrap_err(), Error::Empty);
+        assert_eq!(CsTrailing::from(vec![1, 2]).to_string(), "1,2,");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<String>, _> = serde_json::from_str(r#""a,b""#);
+        assert_eq!(cs.unwrap().0, ["a", "b"]);
+        let cs: Result<CS<String>, _> = serde_json::from_str(r#""a,,b""#);
+        assert!(cs.is_err());
+
+        let s = serde_json::to_string(&CS::<_>::from(vec![1, 2]));
+        assert_eq!(s.unwrap(), r#""1,2""#);
+    }
+}
@@ -0,0 +1,43 @@
+//! Tab separated lists, for TSV-cell style fields.
+//!
+//! ```rust
+//! use serde_cs::tab::TS;
+//!
+//! let cells: TS<String> = serde_json::from_str(r#""id\tname\t\tage""#).unwrap();
+//! assert_eq!(cells.to_string(), "id\tname\tage");
+//! ```
+
+use crate::separator::Tab;
+use crate::vec::CS;
+
+pub type TS<T> = CS<T, Tab>;
+
+#[cfg(test)]
+mod tests {
+    use super::TS;
+    use crate::vec::CS;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<&str>) {
+        let ts: Result<TS<String>, _> = s.parse();
+        assert!(matches!(ts, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str("\t\t", vec![]);
+        assert_ok_from_str("a b\tc,d", vec!["a b", "c,d"]);
+        assert_ok_from_str("\ta\t\tb\t", vec!["a", "b"]);
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(TS::from(vec![1, 2]).to_string(), "1\t2");
+
+        let ts: Result<TS<u32>, _> = serde_json::from_str(r#""1\t2""#);
+        assert_eq!(ts.unwrap().0, [1, 2]);
+
+        let s = serde_json::to_string(&TS::from(vec![1, 2]));
+        assert_eq!(s.unwrap(), r#""1\t2""#);
+    }
+}
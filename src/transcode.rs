@@ -0,0 +1,221 @@
+//! Conversion between comma separated strings and sequences of other formats.
+//!
+//! Segments are passed through as strings, so no typed element is ever built.
+//!
+//! ```rust
+//! use serde_cs::transcode::{Joined, Segments};
+//!
+//! let s = serde_json::to_string(&Segments(",1,,a,")).unwrap();
+//! assert_eq!(s, r#"["1","a"]"#);
+//!
+//! let Joined(cs) = serde_json::from_str(r#"[1,"a",true]"#).unwrap();
+//! assert_eq!(cs, "1,a,true");
+//! ```
+
+use serde::de;
+use serde::ser;
+
+use std::fmt;
+use std::fmt::Write;
+
+/// Serializes a comma separated string as a sequence of its non-empty segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segments<'a>(pub &'a str);
+
+impl ser::Serialize for Segments<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_seq(self.0.split(',').filter(|s| !s.is_empty()))
+    }
+}
+
+/// Deserializes a sequence of scalars into a comma separated string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Joined(pub String);
+
+impl Joined {
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for Joined {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Joined {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = Joined;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("sequence of scalars")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut buf = String::new();
+                while seq.next_element_seed(Append(&mut buf))?.is_some() {}
+                Ok(Joined(buf))
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+struct Append<'a>(&'a mut String);
+
+impl Append<'_> {
+    fn push<E>(self, v: impl fmt::Display) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        if !self.0.is_empty() {
+            self.0.push(',');
+        }
+        let start = self.0.len();
+        write!(self.0, "{}", v).map_err(de::Error::custom)?;
+
+        let value = &self.0[start..];
+        if value.is_empty() || value.contains(',') {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(value),
+                &"non-empty element without commas",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for Append<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for Append<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("string, number, or boolean")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.push(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.push(v)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.push(v)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.push(v)
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.push(v)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.push(v)
+    }
+}
+
+#[cfg(feature = "json")]
+pub fn to_json(s: &str) -> String {
+    serde_json::to_string(&Segments(s)).expect("strings always serialize to JSON")
+}
+
+#[cfg(feature = "json")]
+pub fn from_json(s: &str) -> serde_json::Result<String> {
+    serde_json::from_str(s).map(Joined::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Joined, Segments};
+
+    fn assert_segments(s: &str, expected: &str) {
+        let json = serde_json::to_string(&Segments(s));
+        assert!(matches!(json, Ok(v) if v == expected))
+    }
+
+    #[test]
+    fn segments() {
+        assert_segments("", "[]");
+        assert_segments(",,,,", "[]");
+        assert_segments("1", r#"["1"]"#);
+        assert_segments(",,1,,a,", r#"["1","a"]"#);
+        assert_segments("x y,z", r#"["x y","z"]"#);
+    }
+
+    fn assert_ok_joined(s: &str, expected: &str) {
+        let cs: Result<Joined, _> = serde_json::from_str(s);
+        assert!(matches!(cs, Ok(v) if v.0 == expected))
+    }
+
+    fn assert_err_joined(s: &str) {
+        let cs: Result<Joined, _> = serde_json::from_str(s);
+        assert!(cs.is_err())
+    }
+
+    #[test]
+    fn joined() {
+        assert_ok_joined("[]", "");
+        assert_ok_joined("[1]", "1");
+        assert_ok_joined(r#"[1,"a",-2,1.5,true]"#, "1,a,-2,1.5,true");
+
+        assert_err_joined(r#""1,2""#);
+        assert_err_joined(r#"["a,b"]"#);
+        assert_err_joined(r#"[""]"#);
+        assert_err_joined("[null]");
+        assert_err_joined("[[1]]");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        assert_eq!(super::to_json(",1,,a"), r#"["1","a"]"#);
+        assert_eq!(super::from_json(r#"["1",2]"#).unwrap(), "1,2");
+    }
+}
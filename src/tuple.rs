@@ -0,0 +1,211 @@
+//! Fixed-position records such as `"3,foo,1.5"`, parsed into tuples of up to
+//! twelve elements.
+//!
+//! Every segment is parsed by the type at its position, so empty segments are
+//! kept, and the number of segments must match the tuple's arity exactly.
+//!
+//! ```rust
+//! use serde_cs::tuple::CS;
+//! use serde_cs::Error;
+//!
+//! let cs: CS<(u32, String, f64)> = serde_json::from_str(r#""3,foo,1.5""#).unwrap();
+//! assert_eq!(cs.0, (3, "foo".to_string(), 1.5));
+//! assert_eq!(cs.to_string(), "3,foo,1.5");
+//!
+//! let cs: Result<CS<(u32, String)>, _> = "3,foo,1.5".parse();
+//! assert!(matches!(cs, Err(Error::Arity { expected: 2, found: 3 })));
+//!
+//! let cs: Result<CS<(u32, u32)>, _> = "3,x".parse();
+//! assert!(matches!(cs, Err(Error::Parse(e)) if e.index == 1));
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::Error;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{error, fmt};
+
+/// An element of a record that failed to parse, with the message of its
+/// own error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementError {
+    pub index: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ElementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "element {}: {}", self.index, self.message)
+    }
+}
+
+impl error::Error for ElementError {}
+
+pub struct CS<T, S = Comma>(pub T, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[T, S] CS[T, S](T, PhantomData):
+        Debug, Clone, PartialEq, Eq, Default, From, Inner, InnerMut, Serialize,
+        Deserialize([S: Separator] |f| write!(f, "record separated by {:?}", S::SEP))
+}
+
+/// Splits a record, dropping the separator a [`Trailing`](crate::separator::Trailing)
+/// list ends with.
+fn segments<S: Separator>(s: &str) -> Vec<&str> {
+    let mut segments: Vec<_> = S::split(s).collect();
+    if S::TRAILING && segments.len() > 1 && segments.last() == Some(&"") {
+        segments.pop();
+    }
+    segments
+}
+
+fn parse_at<T, S>(index: usize, s: &str) -> Result<T, Error<ElementError>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    S: Separator,
+{
+    S::CASE.apply(s).parse().map_err(|e: T::Err| {
+        Error::Parse(ElementError {
+            index,
+            message: e.to_string(),
+        })
+    })
+}
+
+macro_rules! tuple_impls {
+    ($len:literal => $i0:tt $T0:ident $($i:tt $T:ident)*) => {
+        impl<$T0, $($T,)* S> FromStr for CS<($T0, $($T,)*), S>
+        where
+            $T0: FromStr,
+            $T0::Err: fmt::Display,
+            $(
+                $T: FromStr,
+                $T::Err: fmt::Display,
+            )*
+            S: Separator,
+        {
+            type Err = Error<ElementError>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let segments = segments::<S>(s);
+                if segments.len() != $len {
+                    return Err(Error::Arity {
+                        expected: $len,
+                        found: segments.len(),
+                    });
+                }
+
+                Ok(Self::from((
+                    parse_at::<$T0, S>($i0, segments[$i0])?,
+                    $(parse_at::<$T, S>($i, segments[$i])?,)*
+                )))
+            }
+        }
+
+        impl<$T0, $($T,)* S> fmt::Display for CS<($T0, $($T,)*), S>
+        where
+            $T0: fmt::Display,
+            $($T: fmt::Display,)*
+            S: Separator,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                S::CASE.fmt(&self.0.$i0, f)?;
+                $(
+                    write_sep::<S>(f)?;
+                    S::CASE.fmt(&self.0.$i, f)?;
+                )*
+
+                if S::TRAILING {
+                    f.write_str(S::SEP)?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+tuple_impls!(1 => 0 A);
+tuple_impls!(2 => 0 A 1 B);
+tuple_impls!(3 => 0 A 1 B 2 C);
+tuple_impls!(4 => 0 A 1 B 2 C 3 D);
+tuple_impls!(5 => 0 A 1 B 2 C 3 D 4 E);
+tuple_impls!(6 => 0 A 1 B 2 C 3 D 4 E 5 F);
+tuple_impls!(7 => 0 A 1 B 2 C 3 D 4 E 5 F 6 G);
+tuple_impls!(8 => 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H);
+tuple_impls!(9 => 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I);
+tuple_impls!(10 => 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J);
+tuple_impls!(11 => 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K);
+tuple_impls!(12 => 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K 11 L);
+
+#[cfg(test)]
+mod tests {
+    use super::{ElementError, CS};
+    use crate::separator::{Pipe, Trailing, Upper};
+    use crate::Error;
+
+    #[test]
+    fn from_str() {
+        let cs: Result<CS<(String,)>, _> = "".parse();
+        assert_eq!(cs.unwrap().0, (String::new(),));
+
+        let cs: Result<CS<(u8, String, bool)>, _> = "1,,true".parse();
+        assert_eq!(cs.unwrap().0, (1, String::new(), true));
+
+        type Twelve = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+        let cs: Result<CS<Twelve>, _> = "1,2,3,4,5,6,7,8,9,10,11,12".parse();
+        let v = cs.unwrap().0;
+        assert_eq!(v.11, 12);
+
+        let cs: Result<CS<(u8, u8)>, _> = "1".parse();
+        assert_eq!(
+            cs.unwrap_err(),
+            Error::Arity {
+                expected: 2,
+                found: 1
+            }
+        );
+        let cs: Result<CS<(u8, u8)>, _> = "1,2,".parse();
+        assert_eq!(
+            cs.unwrap_err(),
+            Error::Arity {
+                expected: 2,
+                found: 3
+            }
+        );
+
+        let cs: Result<CS<(u8, u8)>, _> = "1,300".parse();
+        assert!(matches!(
+            cs,
+            Err(Error::Parse(ElementError { index: 1, .. }))
+        ));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(CS::<_>::from((1, "a", 2.5)).to_string(), "1,a,2.5");
+        assert_eq!(format!("{:#}", CS::<_, Pipe>::from((1, 2))), "1| 2");
+        assert_eq!(CS::<_, Upper<Pipe>>::from(("a", 'b')).to_string(), "A|B");
+    }
+
+    #[test]
+    fn trailing() {
+        let cs: Result<CS<(u8, u8), Trailing<Pipe>>, _> = "1|2|".parse();
+        assert_eq!(cs.as_ref().unwrap().0, (1, 2));
+        assert_eq!(cs.map(|cs| cs.to_string()), Ok("1|2|".to_string()));
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<CS<(u32, String, f64)>, _> = serde_json::from_str(r#""3,foo,1.5""#);
+        assert_eq!(cs.as_ref().unwrap().0, (3, "foo".to_string(), 1.5));
+
+        let s = serde_json::to_string(&cs.unwrap());
+        assert_eq!(s.unwrap(), r#""3,foo,1.5""#);
+
+        let cs: Result<CS<(u32, u32)>, _> = serde_json::from_str(r#""3,x""#);
+        assert!(cs.unwrap_err().to_string().contains("element 1"));
+    }
+}
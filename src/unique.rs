@@ -0,0 +1,264 @@
+//! Order-preserving lists without duplicates.
+//!
+//! ```rust
+//! use serde_cs::unique::{RejectDuplicates, UniqueCS};
+//!
+//! let tags: UniqueCS<String> = serde_json::from_str(r#""b,a,b""#).unwrap();
+//! assert_eq!(tags.as_slice(), ["b", "a"]);
+//!
+//! let res: Result<UniqueCS<String, RejectDuplicates>, _> = serde_json::from_str(r#""b,a,b""#);
+//! assert!(res.is_err());
+//! ```
+
+use crate::Error;
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::{fmt, vec};
+
+/// What to do with an element that is already present.
+pub trait DuplicatePolicy {
+    const REJECT: bool;
+}
+
+/// Silently skips duplicates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DropDuplicates;
+
+impl DuplicatePolicy for DropDuplicates {
+    const REJECT: bool = false;
+}
+
+/// Fails on duplicates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RejectDuplicates;
+
+impl DuplicatePolicy for RejectDuplicates {
+    const REJECT: bool = true;
+}
+
+/// A list that keeps insertion order and indexes its elements by hash, so
+/// membership checks are O(1) on average.
+#[derive(Clone)]
+pub struct UniqueCS<T, P = DropDuplicates> {
+    items: Vec<T>,
+    index: HashMap<u64, Vec<usize>>,
+    hasher: RandomState,
+    _policy: PhantomData<P>,
+}
+
+list_wrapper! {
+    impl[T, P] UniqueCS[T, P]:
+        Serialize, Deserialize(|f| f.write_str("comma separated list of unique values"))
+}
+
+impl<T, P> Default for UniqueCS<T, P> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            index: HashMap::new(),
+            hasher: RandomState::new(),
+            _policy: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for UniqueCS<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UniqueCS").field(&self.items).finish()
+    }
+}
+
+impl<T: PartialEq, P> PartialEq for UniqueCS<T, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<T: Eq, P> Eq for UniqueCS<T, P> {}
+
+impl<T, P> AsRef<[T]> for UniqueCS<T, P> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T, P> Deref for UniqueCS<T, P> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T, P> UniqueCS<T, P> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T: Eq + Hash, P: DuplicatePolicy> UniqueCS<T, P> {
+    fn position(&self, hash: u64, x: &T) -> Option<usize> {
+        self.index
+            .get(&hash)?
+            .iter()
+            .copied()
+            .find(|&i| self.items[i] == *x)
+    }
+
+    #[inline]
+    pub fn contains(&self, x: &T) -> bool {
+        self.position(self.hasher.hash_one(x), x).is_some()
+    }
+
+    /// Appends `value` unless it is already present.
+    ///
+    /// Returns `Ok(false)` when a duplicate is dropped, or hands it back as
+    /// `Err` under [`RejectDuplicates`].
+    pub fn push(&mut self, value: T) -> Result<bool, T> {
+        let hash = self.hasher.hash_one(&value);
+        if self.position(hash, &value).is_some() {
+            return if P::REJECT { Err(value) } else { Ok(false) };
+        }
+
+        self.index.entry(hash).or_default().push(self.items.len());
+        self.items.push(value);
+        Ok(true)
+    }
+}
+
+impl<T: Eq + Hash, P: DuplicatePolicy> TryFrom<Vec<T>> for UniqueCS<T, P> {
+    type Error = T;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        let mut unique = Self::default();
+        for x in v {
+            unique.push(x)?;
+        }
+        Ok(unique)
+    }
+}
+
+impl<T, P> FromStr for UniqueCS<T, P>
+where
+    T: FromStr + Eq + Hash,
+    P: DuplicatePolicy,
+{
+    type Err = Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut unique = Self::default();
+        for s in s.split(',').filter(|s| !s.is_empty()) {
+            unique.push(s.parse()?).map_err(|_| Error::Duplicate)?;
+        }
+        Ok(unique)
+    }
+}
+
+impl<T, P> IntoIterator for UniqueCS<T, P> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<T: fmt::Display, P> fmt::Display for UniqueCS<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.items.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RejectDuplicates, UniqueCS};
+    use crate::Error;
+    type UniqueTest = UniqueCS<u32>;
+    type RejectTest = UniqueCS<u32, RejectDuplicates>;
+
+    fn assert_ok_from_str(s: &str, expected: &[u32]) {
+        let cs: Result<UniqueTest, _> = s.parse();
+        assert!(matches!(cs, Ok(v) if v.as_slice() == expected));
+
+        let cs: Result<RejectTest, _> = s.parse();
+        assert!(matches!(cs, Ok(v) if v.as_slice() == expected));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", &[]);
+        assert_ok_from_str(",,", &[]);
+        assert_ok_from_str("3,1,2", &[3, 1, 2]);
+
+        let cs: Result<UniqueTest, _> = "3,1,3,,2,1".parse();
+        assert_eq!(cs.unwrap().as_slice(), [3, 1, 2]);
+
+        let cs: Result<RejectTest, _> = "3,1,3".parse();
+        assert_eq!(cs.unwrap_err(), Error::Duplicate);
+        assert!("1,a".parse::<UniqueTest>().is_err());
+    }
+
+    #[test]
+    fn push() {
+        let mut cs = UniqueTest::new();
+        assert_eq!(cs.push(1), Ok(true));
+        assert_eq!(cs.push(2), Ok(true));
+        assert_eq!(cs.push(1), Ok(false));
+        assert_eq!(cs.as_slice(), [1, 2]);
+        assert!(cs.contains(&2));
+        assert!(!cs.contains(&3));
+
+        let mut cs = RejectTest::new();
+        assert_eq!(cs.push(1), Ok(true));
+        assert_eq!(cs.push(1), Err(1));
+        assert_eq!(cs.as_slice(), [1]);
+    }
+
+    #[test]
+    fn try_from() {
+        let cs = UniqueTest::try_from(vec![1, 2, 1]);
+        assert_eq!(cs.unwrap().as_slice(), [1, 2]);
+        assert_eq!(RejectTest::try_from(vec![1, 2, 1]), Err(1));
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<UniqueTest, _> = serde_json::from_str(r#""2,1,2""#);
+        assert_eq!(cs.unwrap().as_slice(), [2, 1]);
+        let cs: Result<RejectTest, _> = serde_json::from_str(r#""2,1,2""#);
+        assert!(cs.is_err());
+
+        let cs: UniqueTest = "2,1".parse().unwrap();
+        let s = serde_json::to_string(&cs);
+        assert_eq!(s.unwrap(), r#""2,1""#);
+    }
+}
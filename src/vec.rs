@@ -1,34 +1,61 @@
+use crate::separator::{write_sep, Char, Comma, Separator};
+use crate::view::CsRef;
+
 use serde::de;
 use serde::ser;
 
+use std::marker::PhantomData;
 use std::str::FromStr;
 use std::{fmt, vec};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CS<T>(pub Vec<T>);
+pub struct CS<T, S = Comma>(pub Vec<T>, pub PhantomData<S>);
+
+/// A list separated by the `char` given as `SEP`.
+pub type CSWith<T, const SEP: char> = CS<T, Char<SEP>>;
 
-impl<T> Default for CS<T> {
+impl<T, S> Default for CS<T, S> {
     #[inline]
     fn default() -> Self {
-        Self(Default::default())
+        Self::with_separator(Default::default())
     }
 }
 
-impl<T> AsRef<[T]> for CS<T> {
+impl<T, S> AsRef<[T]> for CS<T, S> {
     #[inline]
     fn as_ref(&self) -> &[T] {
         &self.0
     }
 }
 
-impl<T> From<Vec<T>> for CS<T> {
+impl<T, S> From<Vec<T>> for CS<T, S> {
     #[inline]
     fn from(v: Vec<T>) -> Self {
-        Self(v)
+        Self::with_separator(v)
+    }
+}
+
+impl<T, const N: usize, S> From<[T; N]> for CS<T, S> {
+    #[inline]
+    fn from(v: [T; N]) -> Self {
+        Self::with_separator(v.into())
     }
 }
 
 impl<T> CS<T> {
+    #[inline]
+    pub const fn new(v: Vec<T>) -> Self {
+        Self(v, PhantomData)
+    }
+}
+
+impl<T, S> CS<T, S> {
+    /// Like [`CS::new`], for lists with a separator other than [`Comma`].
+    #[inline]
+    pub const fn with_separator(v: Vec<T>) -> Self {
+        Self(v, PhantomData)
+    }
+
     #[inline]
     pub fn into_inner(self) -> Vec<T> {
         self.0
@@ -45,19 +72,19 @@ impl<T> CS<T> {
     }
 }
 
-impl<T: FromStr> FromStr for CS<T> {
+impl<T: FromStr, S: Separator> FromStr for CS<T, S> {
     type Err = T::Err;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.split(',')
+        S::split(s)
             .filter(|s| !s.is_empty())
-            .map(T::from_str)
+            .map(|s| S::CASE.apply(s).parse())
             .collect::<Result<Vec<_>, _>>()
-            .map(Self)
+            .map(Self::with_separator)
     }
 }
 
-impl<T> IntoIterator for CS<T> {
+impl<T, S> IntoIterator for CS<T, S> {
     type Item = T;
     type IntoIter = vec::IntoIter<T>;
 
@@ -66,49 +93,54 @@ impl<T> IntoIterator for CS<T> {
     }
 }
 
-impl<T: fmt::Display> fmt::Display for CS<T> {
+impl<T: fmt::Display, S: Separator> fmt::Display for CS<T, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut it = self.0.iter();
         if let Some(v) = it.next() {
-            <T as fmt::Display>::fmt(v, f)?;
+            S::CASE.fmt(v, f)?;
         }
 
         for v in it {
-            write!(f, ",{}", v)?
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
         }
 
         Ok(())
     }
 }
 
-impl<T: fmt::Display> ser::Serialize for CS<T> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl<T: fmt::Display, S: Separator> ser::Serialize for CS<T, S> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
     where
-        S: ser::Serializer,
+        Ser: ser::Serializer,
     {
         serializer.serialize_str(&self.to_string())
     }
 }
 
-impl<'de, T> de::Deserialize<'de> for CS<T>
+impl<'de, T, S> de::Deserialize<'de> for CS<T, S>
 where
     T: FromStr,
     T::Err: fmt::Display,
+    S: Separator,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        use std::marker::PhantomData;
+        struct CsVisitor<T, S>(PhantomData<(T, S)>);
 
-        struct CsVisitor<T>(PhantomData<T>);
-
-        impl<'de, T> de::Visitor<'de> for CsVisitor<T>
+        impl<'de, T, S> de::Visitor<'de> for CsVisitor<T, S>
         where
             T: FromStr,
             T::Err: fmt::Display,
+            S: Separator,
         {
-            type Value = CS<T>;
+            type Value = CS<T, S>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("comma separeted list")
@@ -126,6 +158,74 @@ where
     }
 }
 
+/// Serializes a plain `Vec<T>` like [`CS<T>`], for use with
+/// `#[serde(with = "serde_cs::vec")]`.
+///
+/// ```rust
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Query {
+///     #[serde(with = "serde_cs::vec")]
+///     ids: Vec<u32>,
+///     #[serde(default, with = "serde_cs::vec::option")]
+///     tags: Option<Vec<String>>,
+/// }
+///
+/// let q: Query = serde_json::from_str(r#"{"ids":"1,2","tags":null}"#).unwrap();
+/// assert_eq!(q.ids, [1, 2]);
+/// assert_eq!(q.tags, None);
+/// assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"ids":"1,2","tags":null}"#);
+/// ```
+pub fn serialize<T, Ser>(v: &[T], serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+where
+    T: fmt::Display,
+    Ser: ser::Serializer,
+{
+    ser::Serialize::serialize(&CsRef::<T>::new(v), serializer)
+}
+
+/// Deserializes a plain `Vec<T>` like [`CS<T>`], for use with
+/// `#[serde(with = "serde_cs::vec")]`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: de::Deserializer<'de>,
+{
+    <CS<T> as de::Deserialize>::deserialize(deserializer).map(CS::into_inner)
+}
+
+/// `#[serde(with = "serde_cs::vec::option")]` for `Option<Vec<T>>`, where
+/// `None` is a null value.
+pub mod option {
+    use super::CS;
+    use crate::view::CsRef;
+
+    use serde::de;
+    use serde::ser;
+
+    use std::fmt;
+    use std::str::FromStr;
+
+    pub fn serialize<T, Ser>(v: &Option<Vec<T>>, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        T: fmt::Display,
+        Ser: ser::Serializer,
+    {
+        let v = v.as_deref().map(CsRef::<T>::new);
+        ser::Serialize::serialize(&v, serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: de::Deserializer<'de>,
+    {
+        let cs: Option<CS<T>> = de::Deserialize::deserialize(deserializer)?;
+        Ok(cs.map(CS::into_inner))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::CS;
@@ -133,7 +233,7 @@ mod tests {
 
     fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
         let cs: Result<CsTest, _> = s.parse();
-        assert!(matches!(cs, Ok(v) if v == CS(expected)))
+        assert!(matches!(cs, Ok(v) if v == CS::new(expected)))
     }
 
     fn assert_err_from_str(s: &str) {
@@ -161,7 +261,7 @@ mod tests {
     }
 
     fn assert_to_string(values: Vec<u32>, expected: &str) {
-        let cs = CS(values).to_string();
+        let cs = CS::new(values).to_string();
         assert_eq!(cs, expected);
     }
 
@@ -175,7 +275,7 @@ mod tests {
 
     fn assert_ok_des(s: &str, expected: Vec<u32>) {
         let cs: Result<CsTest, _> = serde_json::from_str(s);
-        assert!(matches!(cs, Ok(v) if v == CS(expected)))
+        assert!(matches!(cs, Ok(v) if v == CS::new(expected)))
     }
 
     fn assert_err_des(s: &str) {
@@ -203,7 +303,7 @@ mod tests {
     }
 
     fn assert_ser(values: Vec<u32>, expected: &str) {
-        let cs = serde_json::to_string(&CS(values));
+        let cs = serde_json::to_string(&CS::new(values));
         assert!(matches!(cs, Ok(v) if v == expected))
     }
 
@@ -214,4 +314,200 @@ mod tests {
         assert_ser(vec![1, 2], r#""1,2""#);
         assert_ser(vec![1, 2, 3, 4, 5], r#""1,2,3,4,5""#);
     }
+
+    #[test]
+    fn separator() {
+        use crate::separator::{Semicolon, Tab};
+
+        let cs: Result<CS<u32, Semicolon>, _> = ";1;;2,".parse();
+        assert!(cs.is_err());
+        let cs: Result<CS<u32, Semicolon>, _> = ";1;;2;".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+
+        assert_eq!(CS::<_, Tab>::with_separator(vec![1, 2]).to_string(), "1\t2");
+
+        let cs: Result<CS<String, Semicolon>, _> = serde_json::from_str(r#""a,b;c""#);
+        assert_eq!(cs.unwrap().0, ["a,b", "c"]);
+    }
+
+    #[test]
+    fn multi_char_separator() {
+        use crate::separator::CommaSpace;
+
+        crate::separator!(Bar, " | ");
+
+        let cs: Result<CS<String, CommaSpace>, _> = "a, b,c, , d".parse();
+        assert_eq!(cs.unwrap().0, ["a", "b,c", "d"]);
+
+        let cs: Result<CS<u32, Bar>, _> = serde_json::from_str(r#"" | 1 | 2""#);
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        assert_eq!(
+            CS::<_, Bar>::with_separator(vec![1, 2]).to_string(),
+            "1 | 2"
+        );
+    }
+
+    #[test]
+    fn trailing_separator() {
+        use crate::separator::{Comma, Trailing};
+
+        type CsTrailing = CS<u32, Trailing<Comma>>;
+        assert_eq!(CsTrailing::default().to_string(), "");
+        assert_eq!(CsTrailing::from(vec![1]).to_string(), "1,");
+        assert_eq!(CsTrailing::from(vec![1, 2, 3]).to_string(), "1,2,3,");
+
+        let cs: Result<CsTrailing, _> = serde_json::from_str(r#""1,2,""#);
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        let s = serde_json::to_string(&CsTrailing::from(vec![1, 2]));
+        assert_eq!(s.unwrap(), r#""1,2,""#);
+    }
+
+    #[test]
+    fn trimmed_segments() {
+        use crate::separator::{Comma, Trim};
+
+        type CsTrim = CS<u32, Trim<Comma>>;
+        let cs: Result<CsTrim, _> = " 1 , 2 ,3 ".parse();
+        assert_eq!(cs.unwrap().0, [1, 2, 3]);
+        let cs: Result<CsTrim, _> = serde_json::from_str(r#"" 1 ,  , 2""#);
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        let cs: Result<CsTrim, _> = "1 2".parse();
+        assert!(cs.is_err());
+        assert_eq!(CsTrim::from(vec![1, 2]).to_string(), "1,2");
+    }
+
+    #[test]
+    fn blank_segments() {
+        use crate::separator::{BlankAsEmpty, Comma};
+
+        let cs: Result<CsTest, _> = "1,  ,2".parse();
+        assert!(cs.is_err());
+        let cs: Result<CS<u32, BlankAsEmpty<Comma>>, _> = "1,  ,2,\t".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        let cs: Result<CS<String, BlankAsEmpty<Comma>>, _> = " a , ,b".parse();
+        assert_eq!(cs.unwrap().0, [" a ", "b"]);
+    }
+
+    #[test]
+    fn case_policy() {
+        use crate::separator::{Comma, Lower, Trim, Upper};
+
+        #[derive(Debug, PartialEq)]
+        enum Method {
+            Get,
+            Post,
+        }
+
+        impl std::str::FromStr for Method {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, ()> {
+                match s {
+                    "GET" => Ok(Self::Get),
+                    "POST" => Ok(Self::Post),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        let cs: Result<CS<Method, Upper<Comma>>, _> = "get,Post".parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == [Method::Get, Method::Post]));
+        let cs: Result<CS<Method, Trim<Upper<Comma>>>, _> = " get , post".parse();
+        assert!(matches!(cs, Ok(CS(v, _)) if v == [Method::Get, Method::Post]));
+
+        let cs = CS::<_, Lower<Comma>>::from(vec!["Keep-Alive", "UPGRADE"]);
+        assert_eq!(cs.to_string(), "keep-alive,upgrade");
+        let cs: Result<CS<String, Lower<Comma>>, _> = serde_json::from_str(r#""A,b""#);
+        assert!(matches!(cs, Ok(CS(v, _)) if v == ["a", "b"]));
+    }
+
+    #[test]
+    fn alternate_display() {
+        use crate::separator::{CommaSpace, Space};
+
+        let cs = CS::new(vec![1, 2, 3]);
+        assert_eq!(format!("{}", cs), "1,2,3");
+        assert_eq!(format!("{:#}", cs), "1, 2, 3");
+        assert_eq!(format!("{:#}", CsTest::default()), "");
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""1,2,3""#);
+
+        assert_eq!(
+            format!("{:#}", CS::<_, CommaSpace>::from(vec![1, 2])),
+            "1, 2"
+        );
+        assert_eq!(format!("{:#}", CS::<_, Space>::from(vec![1, 2])), "1 2");
+    }
+
+    #[test]
+    fn spaced_separator() {
+        use crate::separator::{Comma, Spaced};
+
+        type CsSpaced = CS<String, Spaced<Comma>>;
+        let cs = CsSpaced::from(vec!["a".into(), "b".into(), "c".into()]);
+        assert_eq!(cs.to_string(), "a, b, c");
+        assert_eq!(format!("{:#}", cs), "a, b, c");
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""a, b, c""#);
+
+        for s in [r#""a,b,c""#, r#""a, b,  c""#] {
+            let parsed: Result<CsSpaced, _> = serde_json::from_str(s);
+            assert_eq!(parsed.unwrap(), cs);
+        }
+    }
+
+    #[test]
+    fn unicode_separator() {
+        use super::CSWith;
+        use crate::separator::{FullwidthComma, IdeographicComma, MiddleDot};
+
+        let cs: Result<CS<String, IdeographicComma>, _> = "東京、大阪、、名古屋".parse();
+        assert_eq!(cs.unwrap().0, ["東京", "大阪", "名古屋"]);
+        let cs: Result<CS<u32, FullwidthComma>, _> = serde_json::from_str(r#""1，2""#);
+        assert_eq!(cs.unwrap().0, [1, 2]);
+        assert_eq!(CS::<_, MiddleDot>::from(vec!["a", "b"]).to_string(), "a·b");
+
+        let cs: Result<CSWith<String, '🦀'>, _> = "a🦀é🦀".parse();
+        assert_eq!(cs.unwrap().0, ["a", "é"]);
+        assert_eq!(CSWith::<_, '、'>::from(vec![1, 2]).to_string(), "1、2");
+    }
+
+    #[test]
+    fn char_separator() {
+        use super::CSWith;
+
+        let cs: Result<CSWith<u32, ';'>, _> = "1;;2".parse();
+        assert_eq!(cs.unwrap().0, [1, 2]);
+
+        let cs: Result<CSWith<String, '→'>, _> = serde_json::from_str(r#""a→b→""#);
+        assert_eq!(cs.unwrap().0, ["a", "b"]);
+
+        let cs = CSWith::<_, '→'>::with_separator(vec![1, 2]);
+        assert_eq!(serde_json::to_string(&cs).unwrap(), r#""1→2""#);
+    }
+
+    #[test]
+    fn serde_with() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Row {
+            #[serde(with = "crate::vec")]
+            ids: Vec<u32>,
+            #[serde(default, with = "crate::vec::option")]
+            tags: Option<Vec<String>>,
+        }
+
+        let row: Result<Row, _> = serde_json::from_str(r#"{"ids":",1,,2","tags":"a,b"}"#);
+        let expected = Row {
+            ids: vec![1, 2],
+            tags: Some(vec!["a".into(), "b".into()]),
+        };
+        assert_eq!(*row.as_ref().unwrap(), expected);
+
+        let s = serde_json::to_string(&row.unwrap());
+        assert_eq!(s.unwrap(), r#"{"ids":"1,2","tags":"a,b"}"#);
+
+        let row: Result<Row, _> = serde_json::from_str(r#"{"ids":""}"#);
+        let row = row.unwrap();
+        assert!(row.ids.is_empty());
+        assert!(row.tags.is_none());
+        assert!(serde_json::from_str::<Row>(r#"{"ids":"x"}"#).is_err());
+    }
 }
@@ -0,0 +1,194 @@
+//! Serialize-only views that format elements without collecting them into
+//! a [`vec::CS`](crate::vec::CS) first.
+//!
+//! ```rust
+//! use serde_cs::view::CsRef;
+//!
+//! let ids = vec![3, 1, 4];
+//! assert_eq!(serde_json::to_string(&CsRef::new(&ids)).unwrap(), r#""3,1,4""#);
+//! ```
+//!
+//! [`CsIter`] formats whatever an iterator yields, writing each element
+//! straight into the serializer's output:
+//!
+//! ```rust
+//! use serde_cs::view::CsIter;
+//!
+//! let evens = CsIter::new((0..10).filter(|n| n % 2 == 0));
+//! assert_eq!(serde_json::to_string(&evens).unwrap(), r#""0,2,4,6,8""#);
+//! ```
+
+use crate::separator::{write_sep, Comma, Separator};
+use crate::vec;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A borrowed slice, formatted like [`vec::CS`].
+pub struct CsRef<'a, T, S = Comma>(pub &'a [T], pub PhantomData<S>);
+
+list_wrapper! {
+    impl['a, T, S] CsRef['a, T, S](&'a [T], PhantomData):
+        Debug, From, Serialize
+}
+
+impl<T, S> Clone for CsRef<'_, T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, S> Copy for CsRef<'_, T, S> {}
+
+impl<'a, T> CsRef<'a, T> {
+    #[inline]
+    pub const fn new(v: &'a [T]) -> Self {
+        Self(v, PhantomData)
+    }
+}
+
+impl<'a, T, S> CsRef<'a, T, S> {
+    /// Like [`CsRef::new`], for lists with a separator other than [`Comma`].
+    #[inline]
+    pub const fn with_separator(v: &'a [T]) -> Self {
+        Self(v, PhantomData)
+    }
+}
+
+impl<'a, T, S> From<&'a Vec<T>> for CsRef<'a, T, S> {
+    #[inline]
+    fn from(v: &'a Vec<T>) -> Self {
+        Self::with_separator(v)
+    }
+}
+
+impl<'a, T, S> From<&'a vec::CS<T, S>> for CsRef<'a, T, S> {
+    #[inline]
+    fn from(cs: &'a vec::CS<T, S>) -> Self {
+        Self::with_separator(&cs.0)
+    }
+}
+
+impl<T: fmt::Display, S: Separator> fmt::Display for CsRef<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+        if let Some(v) = it.next() {
+            S::CASE.fmt(v, f)?;
+        }
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(v, f)?;
+        }
+
+        if S::TRAILING && !self.0.is_empty() {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The elements yielded by `I`, formatted like [`vec::CS`].
+///
+/// The iterator is cloned on every formatting pass, so cloning it should be
+/// cheap; iterators over ranges or borrowed collections are.
+pub struct CsIter<I, S = Comma>(pub I, pub PhantomData<S>);
+
+list_wrapper! {
+    impl[I, S] CsIter[I, S](I, PhantomData):
+        Debug, Clone, Serialize
+}
+
+impl<I> CsIter<I> {
+    #[inline]
+    pub const fn new(iter: I) -> Self {
+        Self(iter, PhantomData)
+    }
+}
+
+impl<I, S> CsIter<I, S> {
+    /// Like [`CsIter::new`], for lists with a separator other than [`Comma`].
+    #[inline]
+    pub const fn with_separator(iter: I) -> Self {
+        Self(iter, PhantomData)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I, S> fmt::Display for CsIter<I, S>
+where
+    I: IntoIterator + Clone,
+    I::Item: fmt::Display,
+    S: Separator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.clone().into_iter();
+        let Some(v) = it.next() else {
+            return Ok(());
+        };
+        S::CASE.fmt(&v, f)?;
+
+        for v in it {
+            write_sep::<S>(f)?;
+            S::CASE.fmt(&v, f)?;
+        }
+
+        if S::TRAILING {
+            f.write_str(S::SEP)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CsIter, CsRef};
+    use crate::separator::{Pipe, Trailing};
+    use crate::vec;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(CsRef::<u32>::new(&[]).to_string(), "");
+        assert_eq!(CsRef::new(&[1, 2, 3]).to_string(), "1,2,3");
+        assert_eq!(format!("{:#}", CsRef::new(&["a", "b"])), "a, b");
+
+        let v = vec![1, 2];
+        assert_eq!(CsRef::<_, Trailing<Pipe>>::from(&v).to_string(), "1|2|");
+        let cs = vec::CS::<_, Pipe>::with_separator(v);
+        assert_eq!(CsRef::from(&cs).to_string(), cs.to_string());
+    }
+
+    #[test]
+    fn serialize() {
+        #[derive(serde::Serialize)]
+        struct Query<'a> {
+            ids: CsRef<'a, u32>,
+        }
+
+        let ids = vec![7, 8];
+        let s = serde_json::to_string(&Query {
+            ids: CsRef::new(&ids),
+        });
+        assert_eq!(s.unwrap(), r#"{"ids":"7,8"}"#);
+    }
+
+    #[test]
+    fn iter() {
+        assert_eq!(CsIter::new(0..0).to_string(), "");
+        assert_eq!(CsIter::new(1..=3).to_string(), "1,2,3");
+
+        let words = ["a", "b"];
+        let it = CsIter::<_, Trailing<Pipe>>::with_separator(words.iter().map(|w| w.len()));
+        assert_eq!(it.to_string(), "1|1|");
+
+        let s = serde_json::to_string(&CsIter::new((0..1_000_000).step_by(250_000)));
+        assert_eq!(s.unwrap(), r#""0,250000,500000,750000""#);
+    }
+}
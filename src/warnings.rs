@@ -0,0 +1,181 @@
+//! Lenient parsing that records what it had to tidy up.
+//!
+//! Surrounding whitespace is trimmed from each segment and empty segments are
+//! skipped; both are reported as [`Warning`]s instead of failing, so sloppy
+//! input can be logged without being rejected.
+//!
+//! ```rust
+//! use serde_cs::warnings::{WarnCS, Warning};
+//!
+//! let cs: WarnCS<u32> = serde_json::from_str(r#"" 1,,2 ,""#).unwrap();
+//! assert_eq!(cs.values, vec![1, 2]);
+//! assert_eq!(
+//!     cs.warnings,
+//!     vec![
+//!         Warning::Trimmed { index: 0 },
+//!         Warning::Trimmed { index: 1 },
+//!         Warning::EmptySkipped { count: 2 },
+//!     ]
+//! );
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Warning {
+    /// Empty segments were skipped.
+    EmptySkipped { count: usize },
+    /// Whitespace was trimmed around the element at `index`.
+    Trimmed { index: usize },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptySkipped { count } => write!(f, "{} empty segments skipped", count),
+            Self::Trimmed { index } => write!(f, "whitespace trimmed at index {}", index),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarnCS<T> {
+    pub values: Vec<T>,
+    pub warnings: Vec<Warning>,
+}
+
+list_wrapper! {
+    impl[T] WarnCS[T]:
+        Serialize, Deserialize(|f| f.write_str("comma separated list"))
+}
+
+impl<T> Default for WarnCS<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for WarnCS<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self {
+            values,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl<T> WarnCS<T> {
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.values
+    }
+
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+impl<T: FromStr> FromStr for WarnCS<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cs = Self::default();
+        let mut empty = 0;
+
+        for s in s.split(',') {
+            let trimmed = s.trim();
+            if trimmed.is_empty() {
+                empty += 1;
+                continue;
+            }
+            if trimmed.len() != s.len() {
+                cs.warnings.push(Warning::Trimmed {
+                    index: cs.values.len(),
+                });
+            }
+            cs.values.push(trimmed.parse()?);
+        }
+
+        // An empty input is an empty list rather than one empty segment.
+        if !s.is_empty() && empty > 0 {
+            cs.warnings.push(Warning::EmptySkipped { count: empty });
+        }
+        Ok(cs)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for WarnCS<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.values.iter();
+        if let Some(v) = it.next() {
+            <T as fmt::Display>::fmt(v, f)?;
+        }
+
+        for v in it {
+            write!(f, ",{}", v)?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WarnCS, Warning};
+    type WarnTest = WarnCS<u32>;
+
+    fn assert_ok_from_str(s: &str, values: Vec<u32>, warnings: Vec<Warning>) {
+        let cs: Result<WarnTest, _> = s.parse();
+        assert!(matches!(cs, Ok(v) if v == WarnCS { values, warnings }))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![], vec![]);
+        assert_ok_from_str("1,2", vec![1, 2], vec![]);
+        assert_ok_from_str(",,", vec![], vec![Warning::EmptySkipped { count: 3 }]);
+        assert_ok_from_str(
+            "1, ,2",
+            vec![1, 2],
+            vec![Warning::EmptySkipped { count: 1 }],
+        );
+        assert_ok_from_str(
+            "1,2 ,\t3",
+            vec![1, 2, 3],
+            vec![Warning::Trimmed { index: 1 }, Warning::Trimmed { index: 2 }],
+        );
+
+        assert!("1,a".parse::<WarnTest>().is_err());
+    }
+
+    #[test]
+    fn warning_messages() {
+        assert_eq!(
+            Warning::EmptySkipped { count: 3 }.to_string(),
+            "3 empty segments skipped"
+        );
+        assert_eq!(
+            Warning::Trimmed { index: 4 }.to_string(),
+            "whitespace trimmed at index 4"
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<WarnTest, _> = serde_json::from_str(r#""1,,2""#);
+        let v = cs.unwrap();
+        assert_eq!(v.values, [1, 2]);
+        assert!(!v.is_clean());
+
+        let s = serde_json::to_string(&WarnTest::from(vec![1, 2]));
+        assert_eq!(s.unwrap(), r#""1,2""#);
+    }
+}
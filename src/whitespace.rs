@@ -0,0 +1,49 @@
+//! Whitespace separated lists, such as SSH options or systemd unit values.
+//!
+//! Any run of Unicode whitespace separates elements; a single space is
+//! written back.
+//!
+//! ```rust
+//! use serde_cs::whitespace::WS;
+//!
+//! let opts: WS<String> = serde_json::from_str(r#"" -v\t -x\n  -q ""#).unwrap();
+//! assert_eq!(opts.to_string(), "-v -x -q");
+//! ```
+
+use crate::separator::Whitespace;
+use crate::vec::CS;
+
+pub type WS<T> = CS<T, Whitespace>;
+
+#[cfg(test)]
+mod tests {
+    use super::WS;
+    use crate::vec::CS;
+
+    fn assert_ok_from_str(s: &str, expected: Vec<u32>) {
+        let ws: Result<WS<u32>, _> = s.parse();
+        assert!(matches!(ws, Ok(CS(v, _)) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("", vec![]);
+        assert_ok_from_str(" \t\n", vec![]);
+        assert_ok_from_str("1", vec![1]);
+        assert_ok_from_str("  1 \t2\r\n3\u{3000}4 ", vec![1, 2, 3, 4]);
+
+        let ws: Result<WS<u32>, _> = "1,2".parse();
+        assert!(ws.is_err());
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(WS::from(vec![1, 2, 3]).to_string(), "1 2 3");
+
+        let ws: Result<WS<String>, _> = serde_json::from_str(r#""a  b""#);
+        assert_eq!(ws.unwrap().0, ["a", "b"]);
+
+        let s = serde_json::to_string(&WS::from(vec!["a", "b"]));
+        assert_eq!(s.unwrap(), r#""a b""#);
+    }
+}
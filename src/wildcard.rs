@@ -0,0 +1,197 @@
+//! Lists where a lone `*` stands for every possible element.
+//!
+//! ```rust
+//! use serde_cs::wildcard::Wildcard;
+//!
+//! let fields: Wildcard<String> = serde_json::from_str(r#""*""#).unwrap();
+//! assert_eq!(fields, Wildcard::All);
+//!
+//! let fields: Wildcard<String> = serde_json::from_str(r#""a,b""#).unwrap();
+//! assert!(fields.contains(&"a".to_string()));
+//! assert!(!fields.contains(&"c".to_string()));
+//! ```
+//!
+//! A list whose only element is written as `*` would read back as
+//! [`Wildcard::All`], so serializing one fails instead. Its `Display` output
+//! is still `*`.
+
+use crate::vec::CS;
+
+use serde::de;
+use serde::ser;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Wildcard<T> {
+    All,
+    Some(CS<T>),
+}
+
+impl<T> Default for Wildcard<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::Some(Default::default())
+    }
+}
+
+impl<T> From<CS<T>> for Wildcard<T> {
+    #[inline]
+    fn from(v: CS<T>) -> Self {
+        Self::Some(v)
+    }
+}
+
+impl<T> Wildcard<T> {
+    #[inline]
+    pub fn is_all(&self) -> bool {
+        matches!(self, Self::All)
+    }
+
+    #[inline]
+    pub fn as_some(&self) -> Option<&CS<T>> {
+        match self {
+            Self::All => None,
+            Self::Some(v) => Some(v),
+        }
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self {
+            Self::All => true,
+            Self::Some(v) => v.0.contains(x),
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for Wildcard<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "*" {
+            Ok(Self::All)
+        } else {
+            s.parse().map(Self::Some)
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Wildcard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => f.write_str("*"),
+            Self::Some(v) => v.fmt(f),
+        }
+    }
+}
+
+impl<T: fmt::Display> ser::Serialize for Wildcard<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let s = self.to_string();
+        if s == "*" && !self.is_all() {
+            return Err(ser::Error::custom(
+                "a list of a lone `*` element would read back as a wildcard",
+            ));
+        }
+        serializer.serialize_str(&s)
+    }
+}
+
+impl<'de, T> de::Deserialize<'de> for Wildcard<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        use std::marker::PhantomData;
+
+        struct WildcardVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> de::Visitor<'de> for WildcardVisitor<T>
+        where
+            T: FromStr,
+            T::Err: fmt::Display,
+        {
+            type Value = Wildcard<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("`*` or comma separated list")
+            }
+
+            fn visit_str<E>(self, values: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                values.parse().map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(WildcardVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wildcard;
+    use crate::vec::CS;
+    type WildcardTest = Wildcard<u32>;
+
+    fn assert_ok_from_str(s: &str, expected: WildcardTest) {
+        let w: Result<WildcardTest, _> = s.parse();
+        assert!(matches!(w, Ok(v) if v == expected))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_ok_from_str("*", Wildcard::All);
+        assert_ok_from_str("", Wildcard::Some(CS::new(vec![])));
+        assert_ok_from_str("1,2", Wildcard::Some(CS::new(vec![1, 2])));
+
+        assert!("*,1".parse::<WildcardTest>().is_err());
+        assert!(" *".parse::<WildcardTest>().is_err());
+        assert!("a".parse::<WildcardTest>().is_err());
+    }
+
+    #[test]
+    fn contains() {
+        assert!(WildcardTest::All.contains(&1));
+        assert!(Wildcard::Some(CS::new(vec![1, 2])).contains(&2));
+        assert!(!Wildcard::Some(CS::new(vec![1, 2])).contains(&3));
+    }
+
+    #[test]
+    fn serde() {
+        let w: Result<WildcardTest, _> = serde_json::from_str(r#""*""#);
+        assert_eq!(w.unwrap(), Wildcard::All);
+        let w: Result<WildcardTest, _> = serde_json::from_str(r#""1,,2""#);
+        assert_eq!(w.unwrap(), Wildcard::Some(CS::new(vec![1, 2])));
+
+        let s = serde_json::to_string(&WildcardTest::All);
+        assert_eq!(s.unwrap(), r#""*""#);
+        let s = serde_json::to_string(&Wildcard::Some(CS::new(vec![1, 2])));
+        assert_eq!(s.unwrap(), r#""1,2""#);
+    }
+
+    #[test]
+    fn literal_star() {
+        let star = Wildcard::Some(CS::new(vec!["*".to_string()]));
+        assert_eq!(star.to_string(), "*");
+        assert!(serde_json::to_string(&star).is_err());
+
+        let stars = Wildcard::Some(CS::new(vec!["*".to_string(), "*".to_string()]));
+        let s = serde_json::to_string(&stars);
+        assert_eq!(s.unwrap(), r#""*,*""#);
+        let w: Result<Wildcard<String>, _> = serde_json::from_str(r#""*,*""#);
+        assert_eq!(w.ok(), Some(stars));
+    }
+}
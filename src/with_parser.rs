@@ -0,0 +1,97 @@
+//! Deserialization of comma separated lists with an ad-hoc element parser.
+//!
+//! ```rust
+//! use serde::{Deserialize, Deserializer};
+//!
+//! fn trimmed<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u32>, D::Error> {
+//!     serde_cs::deserialize_with_parser(|s: &str| s.trim().parse::<u32>())(deserializer)
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Query {
+//!     #[serde(deserialize_with = "trimmed")]
+//!     ids: Vec<u32>,
+//! }
+//!
+//! let q: Query = serde_json::from_str(r#"{"ids":"1, 2 ,3"}"#).unwrap();
+//! assert_eq!(q.ids, vec![1, 2, 3]);
+//! ```
+
+use serde::de;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Returns a deserializer function splitting a string on commas, skipping
+/// empty segments, and parsing each remaining segment with `f`.
+pub fn deserialize_with_parser<'de, D, F, T, E>(f: F) -> impl FnOnce(D) -> Result<Vec<T>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    F: Fn(&str) -> Result<T, E>,
+    E: fmt::Display,
+{
+    move |deserializer| deserializer.deserialize_str(ParserVisitor(f, PhantomData))
+}
+
+struct ParserVisitor<F, T>(F, PhantomData<T>);
+
+impl<'de, F, T, E> de::Visitor<'de> for ParserVisitor<F, T>
+where
+    F: Fn(&str) -> Result<T, E>,
+    E: fmt::Display,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("comma separated list")
+    }
+
+    fn visit_str<Er>(self, values: &str) -> Result<Self::Value, Er>
+    where
+        Er: de::Error,
+    {
+        values
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .map(|s| (self.0)(s).map_err(de::Error::custom))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deserialize_with_parser;
+
+    use serde::Deserialize;
+
+    fn hex<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserialize_with_parser(|s: &str| u8::from_str_radix(s, 16))(deserializer)
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Colors {
+        #[serde(deserialize_with = "hex")]
+        rgb: Vec<u8>,
+    }
+
+    fn assert_ok_des(s: &str, expected: Vec<u8>) {
+        let colors: Result<Colors, _> = serde_json::from_str(s);
+        assert!(matches!(colors, Ok(v) if v.rgb == expected))
+    }
+
+    fn assert_err_des(s: &str) {
+        let colors: Result<Colors, _> = serde_json::from_str(s);
+        assert!(colors.is_err())
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_ok_des(r#"{"rgb":""}"#, vec![]);
+        assert_ok_des(r#"{"rgb":",,"}"#, vec![]);
+        assert_ok_des(r#"{"rgb":"ff,0,,80"}"#, vec![255, 0, 128]);
+
+        assert_err_des(r#"{"rgb":"ff,g0"}"#);
+        assert_err_des(r#"{"rgb":"100"}"#);
+        assert_err_des(r#"{"rgb":1}"#);
+    }
+}
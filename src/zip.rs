@@ -0,0 +1,224 @@
+//! Two lists carried in one field, either interleaved (`"1,a,2,b"`) or as
+//! `:`-joined pairs (`"1:a,2:b"`).
+//!
+//! ```rust
+//! use serde_cs::vec::CS;
+//! use serde_cs::zip::{Interleaved, Paired};
+//!
+//! let pairs = Paired::zip(CS::new(vec![1, 2]), CS::new(vec!["a", "b"]));
+//! assert_eq!(pairs.to_string(), "1:a,2:b");
+//!
+//! let cs: Interleaved<u32, String> = serde_json::from_str(r#""1,a,2,b""#).unwrap();
+//! let (ids, names) = cs.unzip();
+//! assert_eq!(ids, CS::new(vec![1, 2]));
+//! assert_eq!(names.to_string(), "a,b");
+//! ```
+
+use crate::vec::CS;
+use crate::Error;
+
+use std::str::FromStr;
+use std::{error, fmt, vec};
+
+/// Which half of a pair failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairError<A, B> {
+    First(A),
+    Second(B),
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for PairError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First(e) => e.fmt(f),
+            Self::Second(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<A, B> error::Error for PairError<A, B>
+where
+    A: error::Error + 'static,
+    B: error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::First(e) => Some(e),
+            Self::Second(e) => Some(e),
+        }
+    }
+}
+
+type PairResult<A, B> = Result<(A, B), Error<PairError<<A as FromStr>::Err, <B as FromStr>::Err>>>;
+
+fn parse_pair<A: FromStr, B: FromStr>(a: &str, b: &str) -> PairResult<A, B> {
+    let a = a.parse().map_err(PairError::First)?;
+    let b = b.parse().map_err(PairError::Second)?;
+    Ok((a, b))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interleaved<A, B>(pub Vec<(A, B)>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paired<A, B>(pub Vec<(A, B)>);
+
+macro_rules! pair_list {
+    ($name:ident) => {
+        list_wrapper! {
+            impl[A, B] $name[A, B](Vec<(A, B)>):
+                Default, From, Serialize,
+                Deserialize(|f| f.write_str("comma separated list of pairs"))
+        }
+
+        impl<A, B> $name<A, B> {
+            /// Pairs up the elements of `a` and `b`, stopping at the end of the shorter list.
+            pub fn zip(a: CS<A>, b: CS<B>) -> Self {
+                Self(a.0.into_iter().zip(b.0).collect())
+            }
+
+            pub fn unzip(self) -> (CS<A>, CS<B>) {
+                let (a, b) = self.0.into_iter().unzip();
+                (CS::new(a), CS::new(b))
+            }
+
+            #[inline]
+            pub fn into_inner(self) -> Vec<(A, B)> {
+                self.0
+            }
+        }
+
+        impl<A, B> IntoIterator for $name<A, B> {
+            type Item = (A, B);
+            type IntoIter = vec::IntoIter<(A, B)>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+    };
+}
+
+pair_list!(Interleaved);
+pair_list!(Paired);
+
+impl<A: FromStr, B: FromStr> FromStr for Interleaved<A, B> {
+    type Err = Error<PairError<A::Err, B::Err>>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.split(',').filter(|s| !s.is_empty());
+        let mut v = Vec::new();
+
+        while let Some(a) = it.next() {
+            let b = it.next().ok_or(Error::Unpaired)?;
+            v.push(parse_pair(a, b)?);
+        }
+        Ok(Self(v))
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Interleaved<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (a, b)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{},{}", a, b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<A: FromStr, B: FromStr> FromStr for Paired<A, B> {
+    type Err = Error<PairError<A::Err, B::Err>>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                let (a, b) = s.split_once(':').ok_or(Error::MissingDelimiter)?;
+                parse_pair(a, b)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Paired<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (a, b)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}:{}", a, b)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interleaved, PairError, Paired};
+    use crate::vec::CS;
+    use crate::Error;
+
+    fn assert_ok_interleaved(s: &str, expected: Vec<(u32, char)>) {
+        let cs: Result<Interleaved<u32, char>, _> = s.parse();
+        assert!(matches!(cs, Ok(Interleaved(v)) if v == expected))
+    }
+
+    fn assert_ok_paired(s: &str, expected: Vec<(u32, char)>) {
+        let cs: Result<Paired<u32, char>, _> = s.parse();
+        assert!(matches!(cs, Ok(Paired(v)) if v == expected))
+    }
+
+    #[test]
+    fn interleaved() {
+        assert_ok_interleaved("", vec![]);
+        assert_ok_interleaved(",1,,a,", vec![(1, 'a')]);
+        assert_ok_interleaved("1,a,2,b", vec![(1, 'a'), (2, 'b')]);
+
+        let cs: Result<Interleaved<u32, char>, _> = "1,a,2".parse();
+        assert_eq!(cs.unwrap_err(), Error::Unpaired);
+        let cs: Result<Interleaved<u32, char>, _> = "a,1".parse();
+        assert!(matches!(cs, Err(Error::Parse(PairError::First(_)))));
+        let cs: Result<Interleaved<u32, char>, _> = "1,ab".parse();
+        assert!(matches!(cs, Err(Error::Parse(PairError::Second(_)))));
+
+        assert_eq!(Interleaved(vec![(1, 'a'), (2, 'b')]).to_string(), "1,a,2,b");
+    }
+
+    #[test]
+    fn paired() {
+        assert_ok_paired("", vec![]);
+        assert_ok_paired("1:a,,2:b", vec![(1, 'a'), (2, 'b')]);
+
+        let cs: Result<Paired<u32, char>, _> = "1:a,2".parse();
+        assert_eq!(cs.unwrap_err(), Error::MissingDelimiter);
+        let cs: Result<Paired<u32, char>, _> = "1:a:b".parse();
+        assert!(matches!(cs, Err(Error::Parse(PairError::Second(_)))));
+
+        assert_eq!(Paired(vec![(1, 'a'), (2, 'b')]).to_string(), "1:a,2:b");
+    }
+
+    #[test]
+    fn zip() {
+        let cs = Paired::zip(CS::new(vec![1, 2, 3]), CS::new(vec!['a', 'b']));
+        assert_eq!(cs, Paired(vec![(1, 'a'), (2, 'b')]));
+        assert_eq!(cs.unzip(), (CS::new(vec![1, 2]), CS::new(vec!['a', 'b'])));
+
+        let cs = Interleaved::zip(CS::new(vec![1]), CS::new(vec!['a']));
+        assert_eq!(cs.to_string(), "1,a");
+    }
+
+    #[test]
+    fn serde() {
+        let cs: Result<Paired<u32, char>, _> = serde_json::from_str(r#""1:a""#);
+        assert_eq!(cs.unwrap().0, [(1, 'a')]);
+
+        let s = serde_json::to_string(&Interleaved(vec![(1, 'a')]));
+        assert_eq!(s.unwrap(), r#""1,a""#);
+    }
+}